@@ -1,14 +1,19 @@
 #[macro_use]
 extern crate serde_derive;
 
-use serde::Serialize;
+mod common;
+
 use std::collections::HashMap;
+use std::sync::Arc;
 use structopt::StructOpt;
 
-use mc_sim::drop_list;
-use mc_sim::sim::{Simulation, SimulationGoals, SimulationGoalsBuilder};
+use mc_sim::drop_list::DropList;
+use mc_sim::sim::{Simulation, SimulationGoalsBuilder};
+use mc_sim::stats::{self, BlazeRodDistribution};
 use mc_sim::stream::StreamResults;
 
+use common::OutputFormat;
+
 #[derive(StructOpt)]
 struct Cli {
     #[structopt(short, long, default_value = "32")]
@@ -17,8 +22,12 @@ struct Cli {
     #[structopt(short, long, default_value = "1000000")]
     cycles: u64,
 
-    #[structopt(long, default_value = "./data/blazes.csv")]
-    output_path: String,
+    #[structopt(long, default_value = "csv", possible_values = &["csv", "json"])]
+    format: OutputFormat,
+
+    /// Defaults to ./data/blazes, with the extension of the format.
+    #[structopt(long)]
+    output_path: Option<String>,
 }
 
 fn main() {
@@ -59,23 +68,21 @@ fn main() {
         .add_run(0, 8)
         .goals();
 
-    let simulation = Simulation::new(goals.clone(), args.threads);
+    let simulation = Simulation::new(goals, args.threads);
+    let drop_lists = Arc::clone(simulation.drop_lists());
     let data = simulation.simulate_n_times(args.cycles);
-    let records = count_blaze_rod_simulation_data(&goals, &data);
-    write_simulation_data(&records, args.output_path);
+    let records = count_blaze_rod_simulation_data(&drop_lists.blaze, &data);
+    let format = args.format;
+    let output_path = args
+        .output_path
+        .unwrap_or_else(|| format!("./data/blazes.{}", format.extension()));
+    format.write_records(&records, &output_path);
 }
 
 fn count_blaze_rod_simulation_data(
-    goals: &SimulationGoals,
+    blaze_drop_list: &DropList<BlazeRodDistribution>,
     data: &[StreamResults],
 ) -> Vec<FightRecord> {
-    let blaze_rod_target = goals
-        .streams
-        .iter()
-        .map(|s| s.iter().map(|r| r.target_rods).sum::<u32>())
-        .sum();
-
-    let blaze_drop_list = drop_list::blaze_drop_list(blaze_rod_target);
     let mut table = HashMap::<u32, SimulationRecordData>::new();
 
     for result in data {
@@ -83,7 +90,7 @@ fn count_blaze_rod_simulation_data(
             None => {
                 table.insert(
                     result.total_fights,
-                    SimulationRecordData::new(result.rod_probability(&blaze_drop_list)),
+                    SimulationRecordData::new(result.rod_probability(blaze_drop_list)),
                 );
             }
             Some(record) => record.count += 1,
@@ -97,22 +104,29 @@ fn count_blaze_rod_simulation_data(
             count: v.count,
             frequency: v.count as f64 / data.len() as f64,
             estimated_probability: v.estimated_probability,
+            cumulative_frequency: 0.0,
+            lucky: false,
         })
         .collect();
 
-    records.sort_by(|lhs, rhs| lhs.blazes.cmp(&rhs.blazes));
-    records
-}
-
-fn write_simulation_data<T>(data: &[T], path: String)
-where
-    T: Serialize,
-{
-    let mut writer = csv::Writer::from_path(&path).unwrap();
-
-    for record in data {
-        writer.serialize(record).unwrap();
+    records.sort_by_key(|record| record.blazes);
+
+    // Flag each row as lucky when it took no more than the expected number, and add the running cdf.
+    let expected = blaze_drop_list
+        .distribution()
+        .unwrap()
+        .expected_total_blazes();
+    let counts: Vec<u64> = records.iter().map(|record| record.count).collect();
+
+    for (record, cumulative_frequency) in records
+        .iter_mut()
+        .zip(stats::cumulative_frequencies(&counts))
+    {
+        record.lucky = record.blazes as f64 <= expected;
+        record.cumulative_frequency = cumulative_frequency;
     }
+
+    records
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -149,4 +163,6 @@ struct FightRecord {
     pub estimated_probability: f64,
     pub count: u64,
     pub frequency: f64,
+    pub cumulative_frequency: f64,
+    pub lucky: bool,
 }
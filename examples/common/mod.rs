@@ -0,0 +1,50 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::BufWriter;
+use std::str::FromStr;
+
+/// The file format that the examples write their records out in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Csv,
+    Json,
+}
+
+impl OutputFormat {
+    /// The file extension of the format, used to name the output files.
+    pub fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Csv => "csv",
+            OutputFormat::Json => "json",
+        }
+    }
+
+    /// Writes records to a file, as one CSV row per record or as a JSON array of records.
+    pub fn write_records<T: Serialize>(self, records: &[T], path: &str) {
+        match self {
+            OutputFormat::Csv => {
+                let mut writer = csv::Writer::from_path(path).unwrap();
+
+                for record in records {
+                    writer.serialize(record).unwrap();
+                }
+            }
+            OutputFormat::Json => {
+                let writer = BufWriter::new(File::create(path).unwrap());
+                serde_json::to_writer_pretty(writer, records).unwrap();
+            }
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_ascii_lowercase().as_str() {
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("unknown format {}, expected csv or json", format)),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Instant;
+use structopt::StructOpt;
+
+use mc_sim::drop::{Drop, DropSim};
+use mc_sim::drop_list;
+
+#[derive(StructOpt)]
+struct Cli {
+    #[structopt(short, long, default_value = "100000000")]
+    drops: usize,
+}
+
+fn main() {
+    let args = Cli::from_args();
+    let mut drop_sim = DropSim::new(drop_list::barter_drop_list(10, 10).list_clone());
+
+    let start = Instant::now();
+    let drops: Vec<Drop> = (0..args.drops).map(|_| drop_sim.get_drop()).collect();
+    report("get_drop", drops.len(), &start);
+
+    let start = Instant::now();
+    let drops = drop_sim.get_drops(args.drops);
+    report("get_drops", drops.len(), &start);
+}
+
+fn report(name: &str, drops: usize, start: &Instant) {
+    let elapsed = start.elapsed();
+    println!(
+        "{}: {} drops in {:?}, drops per second: {}",
+        name,
+        drops,
+        elapsed,
+        (drops as f64 / elapsed.as_secs_f64()) as u64,
+    );
+}
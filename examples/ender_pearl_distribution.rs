@@ -1,14 +1,19 @@
 #[macro_use]
 extern crate serde_derive;
 
-use serde::Serialize;
+mod common;
+
 use std::collections::HashMap;
+use std::sync::Arc;
 use structopt::StructOpt;
 
-use mc_sim::drop_list;
-use mc_sim::sim::{Simulation, SimulationGoals, SimulationGoalsBuilder};
+use mc_sim::drop_list::DropList;
+use mc_sim::sim::{Simulation, SimulationGoalsBuilder};
+use mc_sim::stats::{self, EnderPearlDistribution};
 use mc_sim::stream::StreamResults;
 
+use common::OutputFormat;
+
 #[derive(StructOpt)]
 struct Cli {
     #[structopt(short, long, default_value = "32")]
@@ -17,35 +22,33 @@ struct Cli {
     #[structopt(short, long, default_value = "1000000")]
     cycles: u64,
 
-    #[structopt(long, default_value = "./data/barters.csv")]
-    output_path: String,
+    #[structopt(long, default_value = "csv", possible_values = &["csv", "json"])]
+    format: OutputFormat,
+
+    /// Defaults to ./data/barters, with the extension of the format.
+    #[structopt(long)]
+    output_path: Option<String>,
 }
 
 fn main() {
     let args = Cli::from_args();
     let goals = SimulationGoalsBuilder::new().add_runs(17, 10, 0).goals();
 
-    let simulation = Simulation::new(goals.clone(), args.threads);
+    let simulation = Simulation::new(goals, args.threads);
+    let drop_lists = Arc::clone(simulation.drop_lists());
     let data = simulation.simulate_n_times(args.cycles);
-    let records = count_ender_pearl_simulation_data(&goals, &data);
-    write_simulation_data(&records, args.output_path);
+    let records = count_ender_pearl_simulation_data(&drop_lists.barter, &data);
+    let format = args.format;
+    let output_path = args
+        .output_path
+        .unwrap_or_else(|| format!("./data/barters.{}", format.extension()));
+    format.write_records(&records, &output_path);
 }
 
 fn count_ender_pearl_simulation_data(
-    goals: &SimulationGoals,
+    barter_drop_list: &DropList<EnderPearlDistribution>,
     data: &[StreamResults],
 ) -> Vec<BarterRecord> {
-    let ender_pearl_target_total = goals
-        .streams
-        .iter()
-        .map(|s| s.iter().map(|r| r.target_pearls).sum::<u32>())
-        .sum();
-
-    let ender_pearl_target_per_run =
-        ender_pearl_target_total / goals.streams.iter().map(|s| s.len() as u32).sum::<u32>();
-
-    let barter_drop_list =
-        drop_list::barter_drop_list(ender_pearl_target_total, ender_pearl_target_per_run);
     let mut table = HashMap::<u32, SimulationRecordData>::new();
 
     for result in data {
@@ -53,7 +56,7 @@ fn count_ender_pearl_simulation_data(
             None => {
                 table.insert(
                     result.total_barters,
-                    SimulationRecordData::new(result.pearl_probability(&barter_drop_list)),
+                    SimulationRecordData::new(result.pearl_probability(barter_drop_list)),
                 );
             }
             Some(record) => record.count += 1,
@@ -67,22 +70,29 @@ fn count_ender_pearl_simulation_data(
             count: v.count,
             frequency: v.count as f64 / data.len() as f64,
             estimated_probability: v.estimated_probability,
+            cumulative_frequency: 0.0,
+            lucky: false,
         })
         .collect();
 
-    records.sort_by(|lhs, rhs| lhs.barters.cmp(&rhs.barters));
-    records
-}
-
-fn write_simulation_data<T>(data: &[T], path: String)
-where
-    T: Serialize,
-{
-    let mut writer = csv::Writer::from_path(&path).unwrap();
-
-    for record in data {
-        writer.serialize(record).unwrap();
+    records.sort_by_key(|record| record.barters);
+
+    // Flag each row as lucky when it took no more than the expected number, and add the running cdf.
+    let expected = barter_drop_list
+        .distribution()
+        .unwrap()
+        .expected_total_barters();
+    let counts: Vec<u64> = records.iter().map(|record| record.count).collect();
+
+    for (record, cumulative_frequency) in records
+        .iter_mut()
+        .zip(stats::cumulative_frequencies(&counts))
+    {
+        record.lucky = record.barters as f64 <= expected;
+        record.cumulative_frequency = cumulative_frequency;
     }
+
+    records
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -119,4 +129,6 @@ struct BarterRecord {
     pub estimated_probability: f64,
     pub count: u64,
     pub frequency: f64,
+    pub cumulative_frequency: f64,
+    pub lucky: bool,
 }
@@ -0,0 +1,47 @@
+mod common;
+
+use std::sync::Arc;
+use structopt::StructOpt;
+
+use mc_sim::sim::{Simulation, SimulationGoals};
+use mc_sim::stats::{self, JointHistogram};
+
+use common::OutputFormat;
+
+#[derive(StructOpt)]
+struct Cli {
+    #[structopt(short, long, default_value = "32")]
+    threads: u32,
+
+    #[structopt(short, long, default_value = "1000000")]
+    cycles: u64,
+
+    #[structopt(long, default_value = "csv", possible_values = &["csv", "json"])]
+    format: OutputFormat,
+
+    /// Defaults to ./data/joint, with the extension of the format.
+    #[structopt(long)]
+    output_path: Option<String>,
+}
+
+fn main() {
+    let args = Cli::from_args();
+    let goals = SimulationGoals::dream_scenario();
+
+    let simulation = Simulation::new(goals, args.threads);
+    let drop_lists = Arc::clone(simulation.drop_lists());
+    let mut histogram = JointHistogram::new();
+    for results in simulation
+        .simulate_n_times_iter(args.cycles)
+        .take(args.cycles as usize)
+    {
+        histogram.record(&results);
+    }
+    let records = stats::joint_distribution(&histogram, &drop_lists.barter, &drop_lists.blaze);
+
+    let format = args.format;
+    let output_path = args
+        .output_path
+        .unwrap_or_else(|| format!("./data/joint.{}", format.extension()));
+    format.write_records(&records, &output_path);
+}
@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate serde_derive;
+
+mod common;
+
+use std::sync::Arc;
+use structopt::StructOpt;
+
+use mc_sim::sim::{Simulation, SimulationGoals};
+use mc_sim::stats::{self, MetricSummary};
+
+use common::OutputFormat;
+
+#[derive(StructOpt)]
+struct Cli {
+    #[structopt(short, long)]
+    goals: String,
+
+    #[structopt(short, long, default_value = "32")]
+    threads: u32,
+
+    #[structopt(short, long, default_value = "1000000")]
+    cycles: u64,
+
+    #[structopt(long, default_value = "csv", possible_values = &["csv", "json"])]
+    format: OutputFormat,
+
+    /// Defaults to ./data/summary, with the extension of the format.
+    #[structopt(long)]
+    output_path: Option<String>,
+}
+
+fn main() {
+    let args = Cli::from_args();
+    let goals = SimulationGoals::load(&args.goals).unwrap();
+
+    let simulation = Simulation::new(goals, args.threads);
+    let drop_lists = Arc::clone(simulation.drop_lists());
+    let data = simulation.simulate_n_times(args.cycles);
+    let summary = stats::summarize(&data, &drop_lists.barter, &drop_lists.blaze).unwrap();
+
+    println!(
+        "streams simulated: {}, luckiest stream: {}",
+        summary.streams, summary.luckiest
+    );
+
+    let format = args.format;
+    let output_path = args
+        .output_path
+        .unwrap_or_else(|| format!("./data/summary.{}", format.extension()));
+    let records = [
+        SummaryRecord::new("barters", &summary.barters),
+        SummaryRecord::new("fights", &summary.fights),
+    ];
+    format.write_records(&records, &output_path);
+}
+
+#[derive(Debug, Serialize)]
+struct SummaryRecord {
+    pub metric: &'static str,
+    pub mean: f64,
+    pub median: f64,
+    pub min: u32,
+    pub max: u32,
+    pub std_dev: f64,
+}
+
+impl SummaryRecord {
+    pub fn new(metric: &'static str, summary: &MetricSummary) -> Self {
+        Self {
+            metric,
+            mean: summary.mean,
+            median: summary.median,
+            min: summary.min,
+            max: summary.max,
+            std_dev: summary.std_dev,
+        }
+    }
+}
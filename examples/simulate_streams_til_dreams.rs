@@ -1,24 +1,29 @@
+use std::sync::Arc;
 use structopt::StructOpt;
 
-use mc_sim::sim::{Simulation, SimulationGoalsBuilder};
+use mc_sim::sim::{Simulation, SimulationGoals};
+use mc_sim::stats;
 
 #[derive(StructOpt)]
 struct Cli {
     #[structopt(short, long, default_value = "32")]
     threads: u32,
 
-    #[structopt(
-        short,
-        long,
-        default_value = "0.000000000000000000005902209912719003371976488112274"
-    )]
-    p_value: f64,
+    /// The luck to simulate until, which defaults to Dream's luck.
+    #[structopt(short, long)]
+    p_value: Option<f64>,
 }
 
 fn main() {
     let args = Cli::from_args();
-    let goals = SimulationGoalsBuilder::new().add_runs(22, 10, 7).goals();
+    let goals = SimulationGoals::dream_scenario();
 
     let simulation = Simulation::new(goals.clone(), args.threads);
-    simulation.run_to_p_value(args.p_value);
+    let drop_lists = Arc::clone(simulation.drop_lists());
+    let results = simulation.run_to_p_value(args.p_value.unwrap_or_else(stats::dream_luck));
+
+    println!(
+        "{}",
+        results.format_with(&drop_lists.barter, &drop_lists.blaze)
+    );
 }
@@ -0,0 +1,42 @@
+use std::time::Instant;
+use structopt::StructOpt;
+
+use mc_sim::drop::DropSim;
+use mc_sim::drop_list;
+use mc_sim::sim::SimulationGoals;
+use mc_sim::stream::Stream;
+
+#[derive(StructOpt)]
+struct Cli {
+    #[structopt(short, long, default_value = "100000")]
+    streams: u64,
+}
+
+fn main() {
+    let args = Cli::from_args();
+    let goals = SimulationGoals::dream_scenario();
+    let mut barter_drop_sim = DropSim::new(drop_list::barter_drop_list(220, 10).list_clone());
+    let mut blaze_drop_sim = DropSim::new(drop_list::blaze_drop_list(154).list_clone());
+
+    let start = Instant::now();
+    let total_barters: u64 = (0..args.streams)
+        .map(|_| {
+            Stream::simulate(
+                &mut barter_drop_sim,
+                &mut blaze_drop_sim,
+                goals.streams[0].clone(),
+            )
+            .total_barters() as u64
+        })
+        .sum();
+    let elapsed = start.elapsed();
+
+    println!(
+        "smallvec: {}, {} streams in {:?}, streams per second: {}, mean barters: {}",
+        cfg!(feature = "smallvec"),
+        args.streams,
+        elapsed,
+        (args.streams as f64 / elapsed.as_secs_f64()) as u64,
+        total_barters as f64 / args.streams as f64,
+    );
+}
@@ -1,9 +1,23 @@
-use rand::rngs::ThreadRng;
-use rand::Rng;
+use crate::error::McSimError;
+use rand::distributions::Uniform;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use statrs::distribution::{Binomial, ChiSquared, Discrete, Univariate};
 
 /// An item that can be part of a drop table. These are Minecraft items.
 /// This list is incomplete, since it only contains the items involved in piglin barters from 1.16.1 and blaze rods.
-#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Hash)]
+/// With the `minecraft_ids` feature, items serialize as their Minecraft ids rather than their variant names.
+/// ```
+/// # use mc_sim::drop::Item;
+/// let json = serde_json::to_string(&Item::EnderPearl).unwrap();
+/// # #[cfg(feature = "minecraft_ids")]
+/// assert_eq!("\"minecraft:ender_pearl\"", json);
+/// # #[cfg(not(feature = "minecraft_ids"))]
+/// # assert_eq!("\"EnderPearl\"", json);
+/// assert_eq!(Item::EnderPearl, serde_json::from_str::<Item>(&json).unwrap());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(not(feature = "minecraft_ids"), derive(Serialize, Deserialize))]
 pub enum Item {
     None,
     Book,
@@ -26,13 +40,178 @@ pub enum Item {
     BlazeRod,
 }
 
+impl Item {
+    /// Maps a Minecraft item id, with or without the `minecraft:` namespace, to an item.
+    /// ```
+    /// # use mc_sim::drop::Item;
+    /// assert_eq!(Some(Item::EnderPearl), Item::from_minecraft_id("minecraft:ender_pearl"));
+    /// assert_eq!(Some(Item::BlazeRod), Item::from_minecraft_id("blaze_rod"));
+    /// assert_eq!(None, Item::from_minecraft_id("minecraft:diamond"));
+    /// ```
+    pub fn from_minecraft_id(id: &str) -> Option<Item> {
+        let item = match id.strip_prefix("minecraft:").unwrap_or(id) {
+            "air" => Item::None,
+            "book" => Item::Book,
+            "iron_boots" => Item::IronBoots,
+            "potion" => Item::Potion,
+            "splash_potion" => Item::SplashPotion,
+            "iron_nugget" => Item::IronNugget,
+            "quartz" => Item::Quartz,
+            "glowstone_dust" => Item::GlowstoneDust,
+            "magma_cream" => Item::MagmaCream,
+            "ender_pearl" => Item::EnderPearl,
+            "string" => Item::String,
+            "fire_charge" => Item::FireCharge,
+            "gravel" => Item::Gravel,
+            "leather" => Item::Leather,
+            "nether_brick" => Item::MetherBrick,
+            "obsidian" => Item::Obsidian,
+            "crying_obsidian" => Item::CryingObsidian,
+            "soul_sand" => Item::SoulSand,
+            "blaze_rod" => Item::BlazeRod,
+            _ => return None,
+        };
+
+        Some(item)
+    }
+
+    /// The Minecraft item id for this item. [Item::None] maps to `minecraft:air`.
+    /// ```
+    /// # use mc_sim::drop::Item;
+    /// assert_eq!("minecraft:ender_pearl", Item::EnderPearl.to_minecraft_id());
+    /// assert_eq!("minecraft:blaze_rod", Item::BlazeRod.to_minecraft_id());
+    /// assert_eq!(Some(Item::Gravel), Item::from_minecraft_id(Item::Gravel.to_minecraft_id()));
+    /// ```
+    pub fn to_minecraft_id(&self) -> &'static str {
+        match self {
+            Item::None => "minecraft:air",
+            Item::Book => "minecraft:book",
+            Item::IronBoots => "minecraft:iron_boots",
+            Item::Potion => "minecraft:potion",
+            Item::SplashPotion => "minecraft:splash_potion",
+            Item::IronNugget => "minecraft:iron_nugget",
+            Item::Quartz => "minecraft:quartz",
+            Item::GlowstoneDust => "minecraft:glowstone_dust",
+            Item::MagmaCream => "minecraft:magma_cream",
+            Item::EnderPearl => "minecraft:ender_pearl",
+            Item::String => "minecraft:string",
+            Item::FireCharge => "minecraft:fire_charge",
+            Item::Gravel => "minecraft:gravel",
+            Item::Leather => "minecraft:leather",
+            Item::MetherBrick => "minecraft:nether_brick",
+            Item::Obsidian => "minecraft:obsidian",
+            Item::CryingObsidian => "minecraft:crying_obsidian",
+            Item::SoulSand => "minecraft:soul_sand",
+            Item::BlazeRod => "minecraft:blaze_rod",
+        }
+    }
+}
+
+#[cfg(feature = "minecraft_ids")]
+impl serde::Serialize for Item {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_minecraft_id())
+    }
+}
+
+#[cfg(feature = "minecraft_ids")]
+impl<'de> serde::Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        Item::from_minecraft_id(&id)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown minecraft item id: {}", id)))
+    }
+}
+
+/// How the count of a drop is chosen.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub enum CountDistribution {
+    /// Uniformly between the drop's min and max count (inclusive).
+    #[default]
+    Uniform,
+    /// The number of successes from `n` trials that each succeed with probability `p`.
+    /// A drop config with this distribution has a min count of 0 and a max count of `n`.
+    Binomial { n: u32, p: f64 },
+}
+
+// Compares `p` by its bits, so that equality is reflexive and `Eq` holds.
+impl PartialEq for CountDistribution {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (CountDistribution::Uniform, CountDistribution::Uniform) => true,
+            (
+                CountDistribution::Binomial { n, p },
+                CountDistribution::Binomial {
+                    n: other_n,
+                    p: other_p,
+                },
+            ) => n == other_n && p.to_bits() == other_p.to_bits(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for CountDistribution {}
+
+impl CountDistribution {
+    /// The mean count of a drop with this distribution, given the drop's min and max count.
+    /// A binomial count always lies within `0..=n`, so the min and max count don't change its mean.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// assert_eq!(CountDistribution::Uniform.mean(4, 8), 6.0);
+    /// assert_eq!(CountDistribution::Binomial { n: 10, p: 0.25 }.mean(4, 8), 2.5);
+    /// assert_eq!(CountDistribution::Uniform.mean(u32::MAX, u32::MAX), u32::MAX as f64);
+    /// ```
+    pub fn mean(&self, min_count: u32, max_count: u32) -> f64 {
+        match *self {
+            CountDistribution::Uniform => (min_count as f64 + max_count as f64) / 2.0,
+            CountDistribution::Binomial { n, p } => n as f64 * p,
+        }
+    }
+
+    /// The probability of a drop with this distribution having exactly a count, given the drop's min and max count.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// assert_eq!(CountDistribution::Uniform.probability(6, 4, 8), 0.2);
+    /// assert_eq!(CountDistribution::Uniform.probability(9, 4, 8), 0.0);
+    /// assert_eq!(CountDistribution::Binomial { n: 2, p: 0.5 }.probability(1, 4, 8), 0.5);
+    /// ```
+    pub fn probability(&self, count: u32, min_count: u32, max_count: u32) -> f64 {
+        match *self {
+            CountDistribution::Uniform if (min_count..=max_count).contains(&count) => {
+                1.0 / (max_count - min_count + 1) as f64
+            }
+            CountDistribution::Uniform => 0.0,
+            CountDistribution::Binomial { n, p } => Binomial::new(p, n as u64)
+                .map(|binomial| binomial.pmf(count as u64))
+                .unwrap_or(0.0),
+        }
+    }
+
+    /// The largest count a drop with this distribution can have, given the drop's max count.
+    fn max(&self, max_count: u32) -> u32 {
+        match *self {
+            CountDistribution::Uniform => max_count,
+            CountDistribution::Binomial { n, .. } => n,
+        }
+    }
+}
+
 /// The configuration for a drop, but not the drop itself.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct DropConfig {
     pub item: Item,
     pub weight: u32,
     pub min_count: u32,
     pub max_count: u32,
+    #[serde(default)]
+    pub count_dist: CountDistribution,
 }
 
 impl DropConfig {
@@ -52,39 +231,405 @@ impl DropConfig {
             weight,
             min_count,
             max_count,
+            count_dist: CountDistribution::Uniform,
+        }
+    }
+
+    /// Sets how the count of the drop is chosen.
+    /// A binomial count sets the min and max count to the range it can take, `0..=n`.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// let drop_config = DropConfig::new(Item::Gravel, 40, 0, 0)
+    ///     .with_count_distribution(CountDistribution::Binomial { n: 16, p: 0.5 });
+    /// # assert_eq!(CountDistribution::Binomial { n: 16, p: 0.5 }, drop_config.count_dist);
+    /// assert_eq!((0, 16), (drop_config.min_count, drop_config.max_count));
+    /// ```
+    ///
+    /// # Panics
+    /// If a binomial count's `p` isn't between 0 and 1.
+    pub fn with_count_distribution(mut self, count_dist: CountDistribution) -> Self {
+        if let CountDistribution::Binomial { n, p } = count_dist {
+            assert!(
+                (0.0..=1.0).contains(&p),
+                "the binomial count probability must be between 0 and 1"
+            );
+            self.min_count = 0;
+            self.max_count = n;
         }
+
+        self.count_dist = count_dist;
+        self
+    }
+
+    /// The probability of this drop being picked from a drop list with a total weight.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(10, 10);
+    /// let pearls = drop_list.list().iter().find(|config| config.item == Item::EnderPearl).unwrap();
+    /// assert_eq!(pearls.probability(drop_list.total_weight()), 20.0 / 423.0);
+    /// ```
+    pub fn probability(&self, total_weight: u32) -> f64 {
+        self.weight as f64 / total_weight as f64
+    }
+
+    /// The mean count of this drop, according to its count distribution.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// let pearls = DropConfig::new(Item::EnderPearl, 20, 4, 8);
+    /// assert_eq!(pearls.mean_count(), 6.0);
+    /// ```
+    pub fn mean_count(&self) -> f64 {
+        self.count_dist.mean(self.min_count, self.max_count)
     }
 }
 
 /// An item drop. The roll is the exact roll that was made that selected this item from the drop list.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Drop {
     pub roll: u32,
     pub item: Item,
     pub count: u32,
 }
 
+/// A drop, along with the probability of getting exactly that drop. See: [DropSim::rarest_drop]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RareDrop {
+    pub drop: Drop,
+    pub probability: f64,
+}
+
+/// The results of a chi-squared test of the uniformity of a drop simulator's rolls. See: [DropSim::self_test]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SelfTestReport {
+    /// The number of rolls that were made.
+    pub samples: usize,
+    /// The number of distinct rolls, each of which should be equally likely.
+    pub buckets: u32,
+    /// The chi-squared statistic of the counts of each roll.
+    pub chi_squared: f64,
+    /// The probability of a chi-squared statistic at least this large from uniform rolls.
+    /// A very small p-value suggests that the rolls aren't uniform.
+    pub p_value: f64,
+}
+
 /// An item drop simulator. Uses a drop list and uniform random number generation to select drops.
 /// This is based on the decompiled minecraft code and I believe it is an accurate representation of that logic.
 /// Some features of that code have been removed, as they don't play a part in bartering or blaze drops.
 #[derive(Debug)]
 pub struct DropSim {
-    rng: ThreadRng,
+    rng: StdRng,
     drop_list: Vec<DropConfig>,
+    cumulative_weights: Vec<u32>,
     max_roll: u32,
+    /// The probability of each possible count of each drop, which is only built while tracking the rarest drop.
+    count_probabilities: Option<Vec<Vec<f64>>>,
+    rarest_drop: Option<RareDrop>,
 }
 
 impl DropSim {
-    /// Creates a drop simulator.
+    /// Creates a drop simulator, seeded from system entropy.
     pub fn new(drop_list: Vec<DropConfig>) -> Self {
-        let max_roll = drop_list.iter().fold(0, |sum, drop| sum + drop.weight);
+        DropSim::with_rng(drop_list, StdRng::from_entropy())
+    }
+
+    /// Creates a drop simulator from a seed, so that the drops it produces can be reproduced.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// let drop_list = vec![
+    ///     DropConfig::new(Item::Gravel, 20, 8, 32),
+    ///     DropConfig::new(Item::EnderPearl, 10, 4, 8),
+    /// ];
+    ///
+    /// let mut lhs = DropSim::from_seed(drop_list.clone(), 42);
+    /// let mut rhs = DropSim::from_seed(drop_list, 42);
+    /// for _ in 0..100 {
+    ///     assert_eq!(lhs.get_drop(), rhs.get_drop());
+    /// }
+    /// ```
+    pub fn from_seed(drop_list: Vec<DropConfig>, seed: u64) -> Self {
+        DropSim::with_rng(drop_list, StdRng::seed_from_u64(seed))
+    }
+
+    /// The exclusive upper bound of the rolls made to pick drops, which is the total weight of the drop list.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(10, 10);
+    /// let drop_sim = DropSim::new(drop_list.list_clone());
+    /// assert_eq!(drop_sim.max_roll(), drop_list.total_weight());
+    /// ```
+    pub fn max_roll(&self) -> u32 {
+        self.max_roll
+    }
+
+    /// Changes the weight of an item in the drop list, and rebuilds the table used to pick drops.
+    /// Every drop of the item in the drop list is given the new weight.
+    /// This allows a weight to be swept without creating a new drop simulator, which keeps its RNG.
+    /// The drop list is left unchanged, with an error, if the new weight would leave it with no total weight,
+    /// or a total weight too large for a `u32`.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let mut drop_sim = DropSim::from_seed(drop_list::barter_drop_list(10, 10).list_clone(), 9);
+    /// drop_sim.override_weight(Item::EnderPearl, 0).unwrap();
+    ///
+    /// assert_eq!(drop_sim.max_roll(), 403);
+    /// assert!(drop_sim.get_drops(10000).iter().all(|drop| drop.item != Item::EnderPearl));
+    ///
+    /// let mut drop_sim = DropSim::new(vec![DropConfig::new(Item::EnderPearl, 10, 4, 8)]);
+    /// assert!(drop_sim.override_weight(Item::EnderPearl, 0).is_err());
+    /// assert_eq!(drop_sim.max_roll(), 10);
+    ///
+    /// let mut drop_sim = DropSim::new(drop_list::barter_drop_list(10, 10).list_clone());
+    /// assert!(drop_sim.override_weight(Item::EnderPearl, u32::MAX).is_err());
+    /// assert_eq!(drop_sim.max_roll(), 423);
+    /// ```
+    pub fn override_weight(&mut self, item: Item, new_weight: u32) -> Result<(), McSimError> {
+        let cumulative_weights = DropSim::cumulative_weights(self.drop_list.iter().map(|drop| {
+            if drop.item == item {
+                new_weight
+            } else {
+                drop.weight
+            }
+        }))?;
+        let max_roll = cumulative_weights.last().copied().unwrap_or(0);
+
+        if max_roll == 0 {
+            return Err(McSimError::InvalidDropList("the total weight is zero"));
+        }
+
+        for drop in self.drop_list.iter_mut().filter(|drop| drop.item == item) {
+            drop.weight = new_weight;
+        }
+
+        self.cumulative_weights = cumulative_weights;
+        self.max_roll = max_roll;
+        Ok(())
+    }
+
+    /// The probability of getting exactly a drop from this drop simulator, which is the probability of the item being
+    /// picked (its weight over the total weight) times the probability of the drop's count.
+    /// The drop is matched to the drop list by its item rather than its roll, like
+    /// [Run::probability](crate::run::Run::probability), so drops that weren't rolled by this drop simulator can be
+    /// given, and an item that isn't on the drop list has a probability of 0.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// let drop_sim = DropSim::new(vec![
+    ///     DropConfig::new(Item::Gravel, 30, 8, 16),
+    ///     DropConfig::new(Item::EnderPearl, 10, 4, 8),
+    /// ]);
+    ///
+    /// let drop = Drop { roll: 35, item: Item::EnderPearl, count: 8 };
+    /// assert_eq!(drop_sim.drop_probability(&drop), 0.25 * 0.2);
+    ///
+    /// let hand_built = Drop { roll: 0, item: Item::EnderPearl, count: 8 };
+    /// assert_eq!(drop_sim.drop_probability(&hand_built), 0.25 * 0.2);
+    /// assert_eq!(drop_sim.drop_probability(&Drop { roll: 1000, item: Item::Book, count: 1 }), 0.0);
+    /// ```
+    pub fn drop_probability(&self, drop: &Drop) -> f64 {
+        self.drop_list
+            .iter()
+            .enumerate()
+            .filter(|(_, config)| config.item == drop.item)
+            .map(|(index, _)| self.drop_probability_at(index, drop.count))
+            .sum()
+    }
+
+    /// Starts tracking the rarest drop this drop simulator produces. See: [rarest_drop](DropSim::rarest_drop)
+    /// Tracking costs a little for every drop, so it's off unless asked for.
+    pub fn track_rarest_drop(&mut self) {
+        if self.count_probabilities.is_none() {
+            self.count_probabilities = Some(
+                self.drop_list
+                    .iter()
+                    .map(|drop| {
+                        (0..=drop.count_dist.max(drop.max_count))
+                            .map(|count| {
+                                drop.count_dist
+                                    .probability(count, drop.min_count, drop.max_count)
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            );
+        }
+    }
+
+    /// The rarest drop this drop simulator has produced, by [drop_probability](DropSim::drop_probability),
+    /// since it started tracking it with [track_rarest_drop](DropSim::track_rarest_drop).
+    /// This is kept across reseeds.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// let mut drop_sim = DropSim::from_seed(
+    ///     vec![
+    ///         DropConfig::new(Item::Gravel, 100, 1, 1),
+    ///         DropConfig::new(Item::Book, 1, 1, 1),
+    ///         DropConfig::new(Item::Obsidian, 10, 1, 1),
+    ///     ],
+    ///     5,
+    /// );
+    /// drop_sim.get_drops(10000);
+    /// assert_eq!(drop_sim.rarest_drop(), None);
+    ///
+    /// drop_sim.track_rarest_drop();
+    /// drop_sim.get_drops(10000);
+    /// let rarest = drop_sim.rarest_drop().unwrap();
+    /// assert_eq!(rarest.drop.item, Item::Book);
+    /// assert_eq!(rarest.probability, 1.0 / 111.0);
+    /// ```
+    pub fn rarest_drop(&self) -> Option<&RareDrop> {
+        self.rarest_drop.as_ref()
+    }
+
+    /// The drop simulator's RNG, so that its state can be inspected.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(10, 10).list_clone();
+    /// let mut drop_sim = DropSim::from_seed(drop_list.clone(), 42);
+    /// let snapshot = drop_sim.rng().clone();
+    /// let drops = drop_sim.get_drops(100);
+    ///
+    /// // Restoring the snapshot replays the same drops.
+    /// *drop_sim.rng_mut() = snapshot;
+    /// assert_eq!(drop_sim.get_drops(100), drops);
+    /// ```
+    pub fn rng(&self) -> &StdRng {
+        &self.rng
+    }
+
+    /// The drop simulator's RNG, so that its state can be replaced. See: [rng](DropSim::rng)
+    pub fn rng_mut(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    /// Tests the uniformity of the raw rolls that pick drops, with a chi-squared test over every possible roll.
+    /// This advances the RNG, just as getting drops would. Drop lists with only one possible roll always pass.
+    /// Drop lists with no possible rolls, such as those with no total weight, have nothing to test and aren't rolled.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let mut drop_sim = DropSim::from_seed(drop_list::barter_drop_list(10, 10).list_clone(), 1);
+    /// let report = drop_sim.self_test(100000);
+    ///
+    /// assert_eq!(report.buckets, 423);
+    /// assert!(report.p_value > 0.001);
+    ///
+    /// let report = DropSim::new(vec![]).self_test(100);
+    /// assert_eq!((report.samples, report.buckets, report.p_value), (0, 0, 1.0));
+    /// ```
+    pub fn self_test(&mut self, samples: usize) -> SelfTestReport {
+        let buckets = self.max_roll;
+        if buckets == 0 {
+            return SelfTestReport {
+                samples: 0,
+                buckets,
+                chi_squared: 0.0,
+                p_value: 1.0,
+            };
+        }
+
+        let mut counts = vec![0u64; buckets as usize];
+
+        for _ in 0..samples {
+            counts[self.rng.gen_range(0..self.max_roll) as usize] += 1;
+        }
+
+        let expected = samples as f64 / buckets as f64;
+        let chi_squared = counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+
+        let p_value = match ChiSquared::new(buckets.saturating_sub(1) as f64) {
+            Ok(distribution) if buckets > 1 => 1.0 - distribution.cdf(chi_squared),
+            _ => 1.0,
+        };
+
+        SelfTestReport {
+            samples,
+            buckets,
+            chi_squared,
+            p_value,
+        }
+    }
+
+    /// Resets the drop simulator's RNG to the state it would have if it were created from a seed,
+    /// without rebuilding the drop list.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(10, 10).list_clone();
+    /// let mut drop_sim = DropSim::new(drop_list.clone());
+    /// drop_sim.get_drops(100);
+    ///
+    /// drop_sim.reseed(42);
+    /// let mut fresh = DropSim::from_seed(drop_list, 42);
+    /// assert_eq!(drop_sim.get_drops(100), fresh.get_drops(100));
+    /// ```
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Creates a drop simulator with a specific RNG.
+    /// A drop list with a total weight too large for a `u32` can't be rolled from, so it is treated like one with no
+    /// weight, which [try_get_drop](DropSim::try_get_drop) reports as an error.
+    fn with_rng(drop_list: Vec<DropConfig>, rng: StdRng) -> Self {
+        let cumulative_weights =
+            DropSim::cumulative_weights(drop_list.iter().map(|drop| drop.weight))
+                .unwrap_or_default();
+        let max_roll = cumulative_weights.last().copied().unwrap_or(0);
+
         Self {
-            rng: rand::thread_rng(),
+            rng,
             drop_list,
+            cumulative_weights,
             max_roll,
+            count_probabilities: None,
+            rarest_drop: None,
         }
     }
 
+    /// The running total of the weights in a drop list, used to pick drops, or an error if the total doesn't fit in a
+    /// `u32`.
+    fn cumulative_weights(weights: impl Iterator<Item = u32>) -> Result<Vec<u32>, McSimError> {
+        let mut sum: u32 = 0;
+        weights
+            .map(|weight| {
+                sum = sum
+                    .checked_add(weight)
+                    .ok_or(McSimError::InvalidDropList("the total weight is too large"))?;
+                Ok(sum)
+            })
+            .collect()
+    }
+
+    /// The index of the drop in the drop list that a roll picks, which is the first whose running total of weights is
+    /// at least the roll. A roll of 0 is looked up as 1, so that it skips any drops with no weight at the start of the
+    /// drop list, which every other roll already does.
+    fn index_for_roll(&self, roll: u32) -> usize {
+        self.cumulative_weights
+            .partition_point(|&weight| weight < roll.max(1))
+    }
+
+    /// The probability of getting exactly a count of the drop at an index in the drop list.
+    /// The count's probability is looked up from the table kept while tracking the rarest drop, if there is one.
+    fn drop_probability_at(&self, index: usize, count: u32) -> f64 {
+        let config = &self.drop_list[index];
+        let count_probability = match &self.count_probabilities {
+            Some(count_probabilities) => count_probabilities[index]
+                .get(count as usize)
+                .copied()
+                .unwrap_or(0.0),
+            None => config
+                .count_dist
+                .probability(count, config.min_count, config.max_count),
+        };
+
+        config.weight as f64 / self.max_roll as f64 * count_probability
+    }
+
     /// Gets an item drop using the drop list.
     /// ```
     /// # use mc_sim::drop::*;
@@ -117,29 +662,294 @@ impl DropSim {
     /// #     };
     /// # }
     /// ```
+    ///
+    /// The dropped item is the first in the drop list where the roll is within the running total of weights,
+    /// skipping any item with no weight.
+    /// This is looked up with a binary search over a table of those running totals, which is built once
+    /// when the drop simulator is created.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(10, 10).list_clone();
+    /// let mut drop_sim = DropSim::from_seed(drop_list.clone(), 3);
+    ///
+    /// for drop in (0..10000).map(|_| drop_sim.get_drop()) {
+    ///     // Find the item with a linear scan over the drop list, subtracting weights from the roll.
+    ///     let mut weight_remaining = drop.roll as i32;
+    ///     let expected = drop_list
+    ///         .iter()
+    ///         .find(|config| {
+    ///             weight_remaining -= config.weight as i32;
+    ///             weight_remaining <= 0
+    ///         })
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(drop.item, expected.item);
+    /// }
+    ///
+    /// // The book is first in the drop list, so it's the item that a roll of 0 would pick.
+    /// let mut drop_sim = DropSim::from_seed(drop_list, 4);
+    /// drop_sim.override_weight(Item::Book, 0).unwrap();
+    /// assert!(drop_sim.get_drops(10000).iter().all(|drop| drop.item != Item::Book));
+    /// ```
+    ///
+    /// The count of a drop follows its count distribution.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// let drop_list = vec![DropConfig::new(Item::Gravel, 1, 0, 0)
+    ///     .with_count_distribution(CountDistribution::Binomial { n: 20, p: 0.3 })];
+    /// let mut drop_sim = DropSim::from_seed(drop_list, 7);
+    ///
+    /// let counts: Vec<u32> = (0..10000).map(|_| drop_sim.get_drop().count).collect();
+    /// let mean = counts.iter().sum::<u32>() as f64 / counts.len() as f64;
+    /// assert!((mean - 6.0).abs() < 0.1);
+    /// assert!(counts.iter().all(|&count| count <= 20));
+    /// ```
+    ///
+    /// # Panics
+    /// If the total weight of the drop list is zero or too large for a `u32`. See: [try_get_drop](DropSim::try_get_drop)
     pub fn get_drop(&mut self) -> Drop {
+        assert!(
+            self.max_roll > 0,
+            "the total weight of the drop list is zero or too large"
+        );
+
         let roll: u32 = self.rng.gen_range(0..self.max_roll);
-        let mut weight_remaining: i32 = roll as i32;
-        let (_, item, count) = self
-            .drop_list
-            .iter()
-            .find(|drop| {
-                weight_remaining -= drop.weight as i32;
-                weight_remaining <= 0
-            })
-            .map(|drop| {
-                (
-                    weight_remaining,
-                    drop.item,
-                    drop.min_count..=drop.max_count,
-                )
-            })
-            .unwrap();
+        self.drop_for_roll(roll, self.index_for_roll(roll))
+    }
 
-        Drop {
+    /// Gets an item drop like [get_drop](DropSim::get_drop), but with an error rather than a panic if the drop list
+    /// can't produce a drop, such as when its weights are all zero or the counts of the selected drop are invalid.
+    /// This is meant for drop lists supplied by users.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::error::McSimError;
+    /// let mut drop_sim = DropSim::new(vec![DropConfig::new(Item::EnderPearl, 10, 4, 8)]);
+    /// assert_eq!(drop_sim.try_get_drop().unwrap().item, Item::EnderPearl);
+    ///
+    /// let mut weightless = DropSim::new(vec![DropConfig::new(Item::EnderPearl, 0, 4, 8)]);
+    /// assert!(matches!(weightless.try_get_drop(), Err(McSimError::InvalidDropList(_))));
+    ///
+    /// let mut empty = DropSim::new(vec![]);
+    /// assert!(empty.try_get_drop().is_err());
+    ///
+    /// let mut backwards = DropSim::new(vec![DropConfig::new(Item::EnderPearl, 10, 8, 4)]);
+    /// assert!(backwards.try_get_drop().is_err());
+    ///
+    /// let mut heavy = DropSim::new(vec![
+    ///     DropConfig::new(Item::Gravel, u32::MAX, 8, 16),
+    ///     DropConfig::new(Item::EnderPearl, 10, 4, 8),
+    /// ]);
+    /// assert!(matches!(heavy.try_get_drop(), Err(McSimError::InvalidDropList(_))));
+    /// ```
+    pub fn try_get_drop(&mut self) -> Result<Drop, McSimError> {
+        if self.max_roll == 0 {
+            // Tell a total weight that is too large apart from one that is zero.
+            DropSim::cumulative_weights(self.drop_list.iter().map(|drop| drop.weight))?;
+            return Err(McSimError::InvalidDropList("the total weight is zero"));
+        }
+
+        let roll: u32 = self.rng.gen_range(0..self.max_roll);
+        let index = self.index_for_roll(roll);
+
+        let valid = match self.drop_list.get(index) {
+            None => {
+                return Err(McSimError::InvalidDropList(
+                    "no drop was selected by the roll",
+                ))
+            }
+            Some(config) => match config.count_dist {
+                CountDistribution::Uniform => config.min_count <= config.max_count,
+                CountDistribution::Binomial { p, .. } => (0.0..=1.0).contains(&p),
+            },
+        };
+
+        if !valid {
+            return Err(McSimError::InvalidDropList(
+                "the selected drop's count is invalid",
+            ));
+        }
+
+        Ok(self.drop_for_roll(roll, index))
+    }
+
+    /// Makes the drop for a roll, where the index is that of the drop in the drop list that the roll selected.
+    fn drop_for_roll(&mut self, roll: u32, index: usize) -> Drop {
+        let config = &self.drop_list[index];
+
+        let drop = Drop {
             roll,
-            item,
-            count: self.rng.gen_range(count),
+            item: config.item,
+            count: match config.count_dist {
+                CountDistribution::Uniform => {
+                    self.rng.gen_range(config.min_count..=config.max_count)
+                }
+                CountDistribution::Binomial { n, p } => {
+                    (0..n).filter(|_| self.rng.gen_bool(p)).count() as u32
+                }
+            },
+        };
+
+        if self.count_probabilities.is_some() {
+            self.track_drop(index, &drop);
+        }
+
+        drop
+    }
+
+    /// Keeps a drop as the rarest drop, if it is rarer than the rarest so far.
+    fn track_drop(&mut self, index: usize, drop: &Drop) {
+        let probability = self.drop_probability_at(index, drop.count);
+        let rarer = match &self.rarest_drop {
+            Some(rarest) => probability < rarest.probability,
+            None => true,
+        };
+
+        if rarer {
+            self.rarest_drop = Some(RareDrop {
+                drop: drop.clone(),
+                probability,
+            });
         }
     }
+
+    /// Gets a number of item drops using the drop list, as a batch.
+    /// The rolls are all made up front, and batches with more drops than there are possible rolls look the drop for
+    /// each roll up from a table, rather than searching for it. The drops are picked just like
+    /// [get_drop](DropSim::get_drop) picks them, but a seeded drop simulator gives different drops than it would
+    /// one at a time.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(10, 10).list_clone();
+    /// let mut drop_sim = DropSim::from_seed(drop_list.clone(), 5);
+    ///
+    /// for n in [10, 10000] {
+    ///     let drops = drop_sim.get_drops(n);
+    ///     assert_eq!(drops.len(), n);
+    ///
+    ///     for drop in drops {
+    ///         let mut weight_remaining = drop.roll as i32;
+    ///         let expected = drop_list
+    ///             .iter()
+    ///             .find(|config| {
+    ///                 weight_remaining -= config.weight as i32;
+    ///                 weight_remaining <= 0
+    ///             })
+    ///             .unwrap();
+    ///
+    ///         assert_eq!(drop.item, expected.item);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// If the total weight of the drop list is zero or too large for a `u32`.
+    pub fn get_drops(&mut self, n: usize) -> Vec<Drop> {
+        assert!(
+            self.max_roll > 0,
+            "the total weight of the drop list is zero or too large"
+        );
+
+        // The range of the rolls is set up once for the whole batch, rather than for every drop.
+        let rolls: Vec<u32> = (&mut self.rng)
+            .sample_iter(Uniform::new(0, self.max_roll))
+            .take(n)
+            .collect();
+
+        // Building the table costs a binary search for every possible roll, so it's only worth it for large batches.
+        let table: Option<Vec<usize>> = if n >= self.max_roll as usize {
+            Some(
+                (0..self.max_roll)
+                    .map(|roll| self.index_for_roll(roll))
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+        rolls
+            .into_iter()
+            .map(|roll| {
+                let index = match &table {
+                    Some(table) => table[roll as usize],
+                    None => self.index_for_roll(roll),
+                };
+
+                self.drop_for_roll(roll, index)
+            })
+            .collect()
+    }
+
+    /// An endless iterator of item drops using the drop list.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// let mut drop_sim = DropSim::new(drop_list::barter_drop_list(10, 10).list_clone());
+    /// let drops: Vec<Drop> = drop_sim.iter().take(5).collect();
+    /// assert_eq!(drops.len(), 5);
+    ///
+    /// let pearl_drops = drop_sim.iter().take(1000).filter(|drop| drop.item == Item::EnderPearl).count();
+    /// assert!(pearl_drops < 1000);
+    /// ```
+    pub fn iter(&mut self) -> impl Iterator<Item = Drop> + '_ {
+        std::iter::repeat_with(move || self.get_drop())
+    }
+
+    /// An iterator of item drops that stops after the drop for which a stopping rule returns true.
+    /// The rule is given an accumulator, which starts at zero, along with each drop, so that it can count towards a
+    /// target. The drop that the rule stops on is included.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunSim;
+    /// let drop_list = drop_list::barter_drop_list(10, 10).list_clone();
+    /// let mut drop_sim = DropSim::from_seed(drop_list.clone(), 9);
+    /// let mut looping_sim = DropSim::from_seed(drop_list.clone(), 9);
+    /// let mut farming_sim = DropSim::from_seed(drop_list, 9);
+    ///
+    /// for _ in 0..100 {
+    ///     let drops: Vec<Drop> = drop_sim
+    ///         .drops_until(|pearls, drop| {
+    ///             if drop.item == Item::EnderPearl {
+    ///                 *pearls += drop.count;
+    ///             }
+    ///             *pearls >= 10
+    ///         })
+    ///         .collect();
+    ///
+    ///     // Farming for pearls by hand gets the same drops.
+    ///     let (mut expected, mut pearls) = (vec![], 0);
+    ///     while pearls < 10 {
+    ///         let drop = looping_sim.get_drop();
+    ///         if drop.item == Item::EnderPearl {
+    ///             pearls += drop.count;
+    ///         }
+    ///         expected.push(drop);
+    ///     }
+    ///
+    ///     assert_eq!(drops, expected);
+    ///     assert_eq!(&RunSim::farm_for_item(&mut farming_sim, Item::EnderPearl, 10)[..], drops.as_slice());
+    /// }
+    ///
+    /// // Stop after 3 barters, whatever they drop.
+    /// assert_eq!(drop_sim.drops_until(|barters, _| { *barters += 1; *barters == 3 }).count(), 3);
+    /// ```
+    pub fn drops_until<'a, F>(&'a mut self, mut done: F) -> impl Iterator<Item = Drop> + 'a
+    where
+        F: FnMut(&mut u32, &Drop) -> bool + 'a,
+    {
+        let mut accumulator = 0;
+        let mut stopped = false;
+
+        std::iter::from_fn(move || {
+            if stopped {
+                return None;
+            }
+
+            let drop = self.get_drop();
+            stopped = done(&mut accumulator, &drop);
+            Some(drop)
+        })
+    }
 }
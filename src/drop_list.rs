@@ -1,10 +1,14 @@
-use crate::drop::{DropConfig, Item};
+use crate::drop::{CountDistribution, DropConfig, Item};
 use crate::error::McSimError;
+use crate::run::RunGoals;
 use crate::stats::{BlazeRodDistribution, EnderPearlDistribution};
 
+/// The total weight that probabilities are scaled to by [DropList::with_probabilities].
+pub const PROBABILITY_SCALE: u32 = 10_000;
+
 /// Holds a list of drops and a model of the distribution of those drops.
 /// See: [barter_drop_list] and [blaze_drop_list]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DropList<D>
 where
     D: Clone,
@@ -22,6 +26,62 @@ where
         DropList { list, distribution: distribution.map(|d| Some(d)).unwrap_or(None) }
     }
 
+    /// Creates a drop list from drop probabilities rather than integer weights, without a distribution.
+    /// Each entry is an item, the probability of it dropping, and the min and max count of the drop.
+    /// Probabilities are scaled to integer weights out of [PROBABILITY_SCALE] and must sum to 1.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list::*;
+    /// # use mc_sim::stats::EnderPearlDistribution;
+    /// let drop_list = DropList::<EnderPearlDistribution>::with_probabilities(&[
+    ///     (Item::EnderPearl, 0.0473, 4, 8),
+    ///     (Item::Gravel, 0.9527, 8, 16),
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(drop_list.list()[0].weight, 473);
+    /// assert_eq!(drop_list.total_weight(), PROBABILITY_SCALE);
+    /// assert!(drop_list.distribution().is_none());
+    ///
+    /// let mut drop_sim = DropSim::from_seed(drop_list.list_clone(), 473);
+    /// let pearls = drop_sim
+    ///     .get_drops(100000)
+    ///     .iter()
+    ///     .filter(|drop| drop.item == Item::EnderPearl)
+    ///     .count();
+    /// assert!((pearls as f64 / 100000.0 - 0.0473).abs() < 0.003);
+    ///
+    /// assert!(DropList::<EnderPearlDistribution>::with_probabilities(&[(Item::EnderPearl, 0.5, 4, 8)]).is_err());
+    /// ```
+    pub fn with_probabilities(
+        entries: &[(Item, f64, u32, u32)],
+    ) -> Result<DropList<D>, McSimError> {
+        let total: f64 = entries
+            .iter()
+            .map(|&(_, probability, _, _)| probability)
+            .sum();
+        let in_range = entries
+            .iter()
+            .all(|&(_, probability, _, _)| (0.0..=1.0).contains(&probability));
+
+        if !in_range || (total - 1.0).abs() > 1e-3 {
+            return Err(McSimError::InvalidProbabilities(total));
+        }
+
+        let list = entries
+            .iter()
+            .map(|&(item, probability, min_count, max_count)| {
+                let weight = (probability * PROBABILITY_SCALE as f64).round() as u32;
+                DropConfig::new(item, weight, min_count, max_count)
+            })
+            .collect();
+
+        Ok(DropList {
+            list,
+            distribution: None,
+        })
+    }
+
     /// The list of drop configs, used by drop sims to pick what item to drop.
     pub fn list(&self) -> &[DropConfig] {
         &self.list
@@ -36,6 +96,264 @@ where
     pub fn distribution(&self) -> &Option<D> {
         &self.distribution
     }
+
+    /// The total weight of all of the drops in the list.
+    /// The probability of a drop is its weight divided by this total.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let weights = [5, 8, 10, 10, 10, 20, 20, 20, 20, 20, 40, 40, 40, 40, 40, 40, 40];
+    /// assert_eq!(drop_list::barter_drop_list(10, 10).total_weight(), weights.iter().sum::<u32>());
+    /// assert_eq!(drop_list::blaze_drop_list(7).total_weight(), 1);
+    /// ```
+    pub fn total_weight(&self) -> u32 {
+        self.list.iter().map(|drop| drop.weight).sum()
+    }
+}
+
+/// A drop list without its distribution, which can't be serialized, but with the targets to rebuild it from.
+/// See: [DropList::to_serializable]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SerializableDropList {
+    pub list: Vec<DropConfig>,
+    /// The total number of the target item across the stream, or `None` if there was no distribution.
+    pub target_total: Option<u32>,
+    /// The number of the target item per run, for ender pearl distributions.
+    #[serde(default)]
+    pub target_per_run: Option<u32>,
+    /// The successful barters that an ender pearl distribution needs across the stream and for each run,
+    /// if it can't be rebuilt from the targets alone, such as one made with [EnderPearlDistribution::new_per_stream].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub successes: Option<(f64, f64)>,
+}
+
+impl DropList<EnderPearlDistribution> {
+    /// Gets the drop list in a form that can be serialized, with the targets of its distribution.
+    /// Drop lists serialize this way, so that they can be saved and loaded with their distributions.
+    /// ```
+    /// # use mc_sim::drop_list::*;
+    /// # use mc_sim::stats::EnderPearlDistribution;
+    /// let drop_list = barter_drop_list(220, 10);
+    /// let serializable = drop_list.to_serializable();
+    /// assert_eq!((serializable.target_total, serializable.target_per_run), (Some(220), Some(10)));
+    /// assert_eq!(DropList::<EnderPearlDistribution>::from_serializable(serializable).unwrap(), drop_list);
+    ///
+    /// let json = serde_json::to_string(&drop_list).unwrap();
+    /// let loaded: DropList<EnderPearlDistribution> = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(loaded, drop_list);
+    /// ```
+    ///
+    /// Distributions made per stream keep the successful barters they need, since the targets alone aren't enough.
+    /// ```
+    /// # use mc_sim::drop_list::*;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stats::EnderPearlDistribution;
+    /// let drop_list = barter_drop_list_per_stream(&[vec![RunGoals::pearls_only(1)], vec![RunGoals::pearls_only(19)]]);
+    /// let serializable = drop_list.to_serializable();
+    /// assert!(serializable.successes.is_some());
+    ///
+    /// let json = serde_json::to_string(&drop_list).unwrap();
+    /// let loaded: DropList<EnderPearlDistribution> = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(loaded, drop_list);
+    /// ```
+    pub fn to_serializable(&self) -> SerializableDropList {
+        let distribution = self.distribution.as_ref();
+        let successes = distribution.and_then(|d| {
+            let rebuilt = EnderPearlDistribution::new(
+                d.ender_pearl_target_total(),
+                d.ender_pearl_target_per_run(),
+                &self.list,
+            );
+
+            match rebuilt {
+                Ok(rebuilt) if rebuilt == *d => None,
+                _ => Some((d.distribution().r(), d.mean_drops_to_reach_target())),
+            }
+        });
+
+        SerializableDropList {
+            list: self.list_clone(),
+            target_total: distribution.map(|d| d.ender_pearl_target_total()),
+            target_per_run: distribution.map(|d| d.ender_pearl_target_per_run()),
+            successes,
+        }
+    }
+
+    /// Creates a drop list from its serializable form, rebuilding its distribution from the targets,
+    /// or an error if the distribution can't be rebuilt.
+    /// The distribution is rebuilt with [EnderPearlDistribution::new], unless the successful barters it needs are given.
+    /// ```
+    /// # use mc_sim::drop_list::*;
+    /// # use mc_sim::stats::EnderPearlDistribution;
+    /// let mut serializable = barter_drop_list(220, 10).to_serializable();
+    /// serializable.successes = Some((-1.0, 2.0));
+    /// assert!(DropList::<EnderPearlDistribution>::from_serializable(serializable).is_err());
+    /// ```
+    pub fn from_serializable(serializable: SerializableDropList) -> Result<Self, McSimError> {
+        let distribution = match (serializable.target_total, serializable.successes) {
+            (Some(total), Some((successes, mean_drops_to_reach_target))) => {
+                Some(EnderPearlDistribution::from_successes(
+                    total,
+                    serializable.target_per_run.unwrap_or(total),
+                    successes,
+                    mean_drops_to_reach_target,
+                    &serializable.list,
+                )?)
+            }
+            (Some(total), None) => Some(EnderPearlDistribution::new(
+                total,
+                serializable.target_per_run.unwrap_or(total),
+                &serializable.list,
+            )?),
+            (None, _) => None,
+        };
+
+        Ok(DropList {
+            list: serializable.list,
+            distribution,
+        })
+    }
+}
+
+impl DropList<BlazeRodDistribution> {
+    /// Gets the drop list in a form that can be serialized, with the target of its distribution.
+    /// ```
+    /// # use mc_sim::drop_list::*;
+    /// # use mc_sim::stats::BlazeRodDistribution;
+    /// let drop_list = blaze_drop_list(154);
+    /// let json = serde_json::to_string(&drop_list).unwrap();
+    /// let loaded: DropList<BlazeRodDistribution> = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(loaded, drop_list);
+    /// assert_eq!(loaded.to_serializable().target_total, Some(154));
+    /// ```
+    pub fn to_serializable(&self) -> SerializableDropList {
+        SerializableDropList {
+            list: self.list_clone(),
+            target_total: self.distribution.as_ref().map(|d| d.blaze_rod_target()),
+            target_per_run: None,
+            successes: None,
+        }
+    }
+
+    /// Creates a drop list from its serializable form, rebuilding its distribution from the target,
+    /// or an error if the distribution can't be rebuilt.
+    pub fn from_serializable(serializable: SerializableDropList) -> Result<Self, McSimError> {
+        let distribution = match serializable.target_total {
+            Some(total) => Some(BlazeRodDistribution::new(total, &serializable.list)?),
+            None => None,
+        };
+
+        Ok(DropList {
+            list: serializable.list,
+            distribution,
+        })
+    }
+}
+
+impl serde::Serialize for DropList<EnderPearlDistribution> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_serializable().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DropList<EnderPearlDistribution> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SerializableDropList::deserialize(deserializer).and_then(|serializable| {
+            Self::from_serializable(serializable).map_err(serde::de::Error::custom)
+        })
+    }
+}
+
+impl serde::Serialize for DropList<BlazeRodDistribution> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_serializable().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DropList<BlazeRodDistribution> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SerializableDropList::deserialize(deserializer).and_then(|serializable| {
+            Self::from_serializable(serializable).map_err(serde::de::Error::custom)
+        })
+    }
+}
+
+/// Builds a custom drop list, paired with the distribution for its target item.
+#[derive(Debug, Clone, Default)]
+pub struct DropListBuilder {
+    list: Vec<DropConfig>,
+}
+
+impl DropListBuilder {
+    /// Create a drop list builder.
+    /// ```
+    /// # use mc_sim::drop::Item;
+    /// # use mc_sim::drop_list::*;
+    /// let drop_list = DropListBuilder::new()
+    ///     .add(Item::Book, 5, 1, 1)
+    ///     .add(Item::IronBoots, 8, 1, 1)
+    ///     .add(Item::Potion, 10, 1, 1)
+    ///     .add(Item::SplashPotion, 10, 1, 1)
+    ///     .add(Item::IronNugget, 10, 9, 36)
+    ///     .add(Item::Quartz, 20, 8, 16)
+    ///     .add(Item::GlowstoneDust, 20, 5, 12)
+    ///     .add(Item::MagmaCream, 20, 2, 6)
+    ///     .add(Item::EnderPearl, 20, 4, 8)
+    ///     .add(Item::String, 20, 8, 24)
+    ///     .add(Item::FireCharge, 40, 1, 5)
+    ///     .add(Item::Gravel, 40, 8, 16)
+    ///     .add(Item::Leather, 40, 4, 10)
+    ///     .add(Item::MetherBrick, 40, 4, 16)
+    ///     .add(Item::Obsidian, 40, 1, 1)
+    ///     .add(Item::CryingObsidian, 40, 1, 3)
+    ///     .add(Item::SoulSand, 40, 4, 16)
+    ///     .build_with_pearl_distribution(220, 10);
+    ///
+    /// assert_eq!(drop_list, barter_drop_list(220, 10));
+    /// ```
+    pub fn new() -> Self {
+        Self { list: Vec::new() }
+    }
+
+    /// Add a drop to the list.
+    pub fn add(mut self, item: Item, weight: u32, min_count: u32, max_count: u32) -> Self {
+        self.list
+            .push(DropConfig::new(item, weight, min_count, max_count));
+        self
+    }
+
+    /// Get the drop list with an ender pearl distribution, consumes the builder.
+    pub fn build_with_pearl_distribution(
+        self,
+        ender_pearl_target_total: u32,
+        ender_pearl_target_per_run: u32,
+    ) -> DropList<EnderPearlDistribution> {
+        let distribution = EnderPearlDistribution::new(
+            ender_pearl_target_total,
+            ender_pearl_target_per_run,
+            &self.list,
+        );
+
+        DropList::new(self.list, distribution)
+    }
+
+    /// Get the drop list with a blaze rod distribution, consumes the builder.
+    /// ```
+    /// # use mc_sim::drop::Item;
+    /// # use mc_sim::drop_list::*;
+    /// let drop_list = DropListBuilder::new()
+    ///     .add(Item::BlazeRod, 1, 0, 1)
+    ///     .build_with_rod_distribution(211);
+    ///
+    /// assert_eq!(drop_list, blaze_drop_list(211));
+    /// ```
+    pub fn build_with_rod_distribution(
+        self,
+        blaze_rod_target: u32,
+    ) -> DropList<BlazeRodDistribution> {
+        let distribution = BlazeRodDistribution::new(blaze_rod_target, &self.list);
+
+        DropList::new(self.list, distribution)
+    }
 }
 
 /// The drop list for piglin barters in Minecraft 1.16.1
@@ -43,7 +361,37 @@ pub fn barter_drop_list(
     ender_pearl_target_total: u32,
     ender_pearl_target_per_run: u32,
 ) -> DropList<EnderPearlDistribution> {
-    let list = vec![
+    let list = barter_drop_configs();
+    let distribution =
+        EnderPearlDistribution::new(ender_pearl_target_total, ender_pearl_target_per_run, &list);
+
+    DropList::new(list, distribution)
+}
+
+/// The drop list for piglin barters in Minecraft 1.16.1, with a distribution over a set of streams that can each have
+/// a different number of runs and pearl targets. See: [EnderPearlDistribution::new_per_stream]
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::run::RunGoals;
+/// let streams = vec![vec![RunGoals::new(10, 7); 4], vec![RunGoals::new(10, 7); 2]];
+/// let drop_list = drop_list::barter_drop_list_per_stream(&streams);
+/// let distribution = drop_list.distribution().unwrap();
+///
+/// // When every run has the same target, this is the same distribution as for the total and per run target.
+/// let averaged = drop_list::barter_drop_list(60, 10).distribution().unwrap();
+/// assert_eq!(distribution.ender_pearl_target_total(), 60);
+/// assert!((distribution.expected_failures() - averaged.expected_failures()).abs() < 1e-9);
+/// ```
+pub fn barter_drop_list_per_stream(streams: &[Vec<RunGoals>]) -> DropList<EnderPearlDistribution> {
+    let list = barter_drop_configs();
+    let distribution = EnderPearlDistribution::new_per_stream(streams, &list);
+
+    DropList::new(list, distribution)
+}
+
+/// The drops for piglin barters in Minecraft 1.16.1
+fn barter_drop_configs() -> Vec<DropConfig> {
+    vec![
         DropConfig::new(Item::Book, 5, 1, 1),
         DropConfig::new(Item::IronBoots, 8, 1, 1),
         DropConfig::new(Item::Potion, 10, 1, 1),
@@ -61,12 +409,7 @@ pub fn barter_drop_list(
         DropConfig::new(Item::Obsidian, 40, 1, 1),
         DropConfig::new(Item::CryingObsidian, 40, 1, 3),
         DropConfig::new(Item::SoulSand, 40, 4, 16),
-    ];
-
-    let distribution =
-        EnderPearlDistribution::new(ender_pearl_target_total, ender_pearl_target_per_run, &list);
-
-    DropList::new(list, distribution)
+    ]
 }
 
 /// The drop list for blaze fights in Minecraft 1.16.1
@@ -76,3 +419,251 @@ pub fn blaze_drop_list(blaze_rod_target: u32) -> DropList<BlazeRodDistribution>
 
     DropList::new(list, distribution)
 }
+
+/// A drop list for blaze fights where each fight drops a single blaze rod with a given chance (from 0 to 1),
+/// for servers that change the vanilla rate of 50%. Fails if the chance is outside of the range 0 (exclusive) to 1.
+/// The chance is represented by weighting the blaze rod against [Item::None], scaled like
+/// [with_probabilities](DropList::with_probabilities).
+/// ```
+/// # use mc_sim::drop::Item;
+/// # use mc_sim::drop_list;
+/// # use mc_sim::stats;
+/// let drop_list = drop_list::blaze_drop_list_with_rate(7, 0.25).unwrap();
+/// let list = drop_list.list();
+/// assert_eq!(
+///     stats::item_drop_probability(list, Item::BlazeRod) * stats::item_drop_average(list, Item::BlazeRod),
+///     0.25
+/// );
+///
+/// // A quarter of the vanilla rate of rods takes twice as many kills.
+/// let vanilla = drop_list::blaze_drop_list(7).distribution().unwrap().expected_total_blazes();
+/// let expected = drop_list.distribution().unwrap().expected_total_blazes();
+/// assert_eq!(expected, 2.0 * vanilla);
+///
+/// // Blazes that never drop rods, or drop more than one per fight, can't be modelled.
+/// assert!(drop_list::blaze_drop_list_with_rate(7, 0.0).is_err());
+/// assert!(drop_list::blaze_drop_list_with_rate(7, 1.5).is_err());
+/// ```
+pub fn blaze_drop_list_with_rate(
+    blaze_rod_target: u32,
+    drop_chance: f64,
+) -> Result<DropList<BlazeRodDistribution>, McSimError> {
+    if !(drop_chance > 0.0 && drop_chance <= 1.0) {
+        return Err(McSimError::InvalidDropChance(drop_chance));
+    }
+
+    let rod_weight = (drop_chance * PROBABILITY_SCALE as f64).round() as u32;
+    let list = vec![
+        DropConfig::new(Item::BlazeRod, rod_weight, 1, 1),
+        DropConfig::new(
+            Item::None,
+            PROBABILITY_SCALE.saturating_sub(rod_weight),
+            0,
+            0,
+        ),
+    ];
+    let distribution = BlazeRodDistribution::new(blaze_rod_target, &list);
+
+    Ok(DropList::new(list, distribution))
+}
+
+/// A loot table in Minecraft's data pack JSON format.
+#[derive(Deserialize)]
+struct LootTable {
+    #[serde(default)]
+    pools: Vec<LootPool>,
+}
+
+/// A pool of weighted entries in a loot table, which drops `rolls` of its entries.
+#[derive(Deserialize)]
+struct LootPool {
+    #[serde(default = "default_loot_rolls")]
+    rolls: LootCount,
+    #[serde(default)]
+    entries: Vec<LootEntry>,
+}
+
+/// A single weighted entry in a loot pool.
+#[derive(Deserialize)]
+struct LootEntry {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default = "default_loot_weight")]
+    weight: u32,
+    #[serde(default)]
+    functions: Vec<LootFunction>,
+}
+
+/// A function applied to a loot entry. Only `set_count` is used, anything else is ignored.
+#[derive(Deserialize)]
+struct LootFunction {
+    function: String,
+    #[serde(default)]
+    count: Option<LootCount>,
+}
+
+/// The count of a `set_count` function, either a constant, a uniform range, or a binomial distribution.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LootCount {
+    Constant(f64),
+    Range { min: f64, max: f64 },
+    Binomial { n: f64, p: f64 },
+    Value { value: f64 },
+}
+
+fn default_loot_weight() -> u32 {
+    1
+}
+
+fn default_loot_rolls() -> LootCount {
+    LootCount::Constant(1.0)
+}
+
+impl LootCount {
+    /// The min count, max count and count distribution of a drop, or an error if the count isn't a valid one.
+    fn bounds(&self) -> Result<(u32, u32, CountDistribution), McSimError> {
+        match *self {
+            LootCount::Constant(count) | LootCount::Value { value: count } => {
+                let count = LootCount::whole_count(count)?;
+                Ok((count, count, CountDistribution::Uniform))
+            }
+            LootCount::Range { min, max } => {
+                let (min, max) = (LootCount::whole_count(min)?, LootCount::whole_count(max)?);
+                if min > max {
+                    return Err(McSimError::InvalidDropList(
+                        "a count range has a min above its max",
+                    ));
+                }
+
+                Ok((min, max, CountDistribution::Uniform))
+            }
+            LootCount::Binomial { n, p } => {
+                let n = LootCount::whole_count(n)?;
+                if !(0.0..=1.0).contains(&p) {
+                    return Err(McSimError::InvalidDropList(
+                        "a binomial count has a probability outside of 0 to 1",
+                    ));
+                }
+
+                Ok((0, n, CountDistribution::Binomial { n, p }))
+            }
+        }
+    }
+
+    /// A count as a whole number, or an error if it isn't a finite, non-negative whole number that fits in a `u32`.
+    fn whole_count(count: f64) -> Result<u32, McSimError> {
+        if count.is_finite() && count >= 0.0 && count.fract() == 0.0 && count <= u32::MAX as f64 {
+            Ok(count as u32)
+        } else {
+            Err(McSimError::InvalidDropList(
+                "a count isn't a non-negative whole number",
+            ))
+        }
+    }
+}
+
+impl LootEntry {
+    fn into_drop_config(self) -> Result<DropConfig, McSimError> {
+        let item = self
+            .name
+            .as_deref()
+            .and_then(Item::from_minecraft_id)
+            .unwrap_or(Item::None);
+
+        let (min_count, max_count, count_dist) = self
+            .functions
+            .iter()
+            .filter(|f| f.function == "minecraft:set_count" || f.function == "set_count")
+            .filter_map(|f| f.count.as_ref())
+            .map(LootCount::bounds)
+            .next_back()
+            .unwrap_or(Ok((1, 1, CountDistribution::Uniform)))?;
+
+        Ok(DropConfig::new(item, self.weight, min_count, max_count)
+            .with_count_distribution(count_dist))
+    }
+}
+
+/// Imports the drop configs from a Minecraft loot table JSON.
+/// Each entry's weight and `set_count` range become a drop config; entries without a `set_count` drop exactly one item.
+/// Items that aren't known to the simulator become [Item::None].
+/// A drop list is a single roll from a single pool, so tables with more than one pool, or a pool that isn't rolled
+/// exactly once, are rejected, as are counts that aren't whole numbers or valid ranges.
+/// ```
+/// # use mc_sim::drop::Item;
+/// # use mc_sim::drop_list::from_minecraft_loot_table;
+/// let json = r#"{
+///   "type": "minecraft:barter",
+///   "pools": [{
+///     "rolls": 1,
+///     "entries": [
+///       {
+///         "type": "minecraft:item",
+///         "weight": 5,
+///         "functions": [{ "function": "minecraft:enchant_randomly" }],
+///         "name": "minecraft:book"
+///       },
+///       {
+///         "type": "minecraft:item",
+///         "weight": 20,
+///         "functions": [{
+///           "function": "minecraft:set_count",
+///           "count": { "min": 4.0, "max": 8.0, "type": "minecraft:uniform" }
+///         }],
+///         "name": "minecraft:ender_pearl"
+///       },
+///       {
+///         "type": "minecraft:item",
+///         "weight": 40,
+///         "name": "minecraft:obsidian"
+///       }
+///     ]
+///   }]
+/// }"#;
+///
+/// let list = from_minecraft_loot_table(json).unwrap();
+/// assert_eq!(3, list.len());
+/// assert_eq!(Item::Book, list[0].item);
+/// assert_eq!((Item::EnderPearl, 20, 4, 8), (list[1].item, list[1].weight, list[1].min_count, list[1].max_count));
+/// assert_eq!((Item::Obsidian, 40, 1, 1), (list[2].item, list[2].weight, list[2].min_count, list[2].max_count));
+/// assert!(from_minecraft_loot_table("not json").is_err());
+///
+/// // A pearl entry with a given count, in a table with the given pools.
+/// let table = |count: &str, pools: &str| {
+///     let entry = format!(r#"{{ "name": "minecraft:ender_pearl", "functions": [{{ "function": "set_count", "count": {} }}] }}"#, count);
+///     format!(r#"{{ "pools": [{}] }}"#, pools.replace("ENTRY", &entry))
+/// };
+/// let pool = r#"{ "rolls": 1, "entries": [ENTRY] }"#;
+/// assert!(from_minecraft_loot_table(&table("2.0", pool)).is_ok());
+/// assert!(from_minecraft_loot_table(&table("-2.0", pool)).is_err());
+/// assert!(from_minecraft_loot_table(&table("2.5", pool)).is_err());
+/// assert!(from_minecraft_loot_table(&table(r#"{ "min": 8.0, "max": 4.0 }"#, pool)).is_err());
+/// assert!(from_minecraft_loot_table(&table(r#"{ "n": 3.0, "p": 1.5 }"#, pool)).is_err());
+/// assert!(from_minecraft_loot_table(&table("2.0", r#"{ "rolls": 2, "entries": [ENTRY] }"#)).is_err());
+/// assert!(from_minecraft_loot_table(&table("2.0", &format!("{}, {}", pool, pool))).is_err());
+/// ```
+pub fn from_minecraft_loot_table(json: &str) -> Result<Vec<DropConfig>, McSimError> {
+    let table: LootTable = serde_json::from_str(json)?;
+
+    if table.pools.len() > 1 {
+        return Err(McSimError::InvalidDropList(
+            "a loot table with more than one pool can't be a single drop list",
+        ));
+    }
+
+    let mut drop_list = Vec::new();
+    for pool in table.pools {
+        if !matches!(pool.rolls.bounds()?, (1, 1, CountDistribution::Uniform)) {
+            return Err(McSimError::InvalidDropList(
+                "a loot pool that isn't rolled exactly once can't be a single drop list",
+            ));
+        }
+
+        for entry in pool.entries {
+            drop_list.push(entry.into_drop_config()?);
+        }
+    }
+
+    Ok(drop_list)
+}
@@ -1,7 +1,85 @@
-
 quick_error! {
+    /// The errors produced by the simulator.
+    /// ```
+    /// # use mc_sim::error::McSimError;
+    /// fn open_results() -> Result<std::fs::File, McSimError> {
+    ///     Ok(std::fs::File::open("this/file/does/not/exist.json")?)
+    /// }
+    ///
+    /// fn run() -> Result<(), Box<dyn std::error::Error>> {
+    ///     open_results()?;
+    ///     Ok(())
+    /// }
+    ///
+    /// let error = open_results().unwrap_err();
+    /// assert!(matches!(error, McSimError::Io(_)));
+    /// assert!(error.to_string().starts_with("io error: "));
+    /// assert!(!run().unwrap_err().to_string().is_empty());
+    /// ```
     #[derive(Debug)]
     pub enum McSimError {
-        InvalidDistribution
+        InvalidDistribution { reason: String, err: statrs::StatsError } {
+            source(err)
+            display("invalid distribution, {}: {}", reason, err)
+        }
+        EmptyResults {
+            display("no stream results were provided")
+        }
+        NotEnoughResults(needed: usize, provided: usize) {
+            display("at least {} stream results are needed, but {} were provided", needed, provided)
+        }
+        InvalidPercentile(p: f64) {
+            display("percentile {} is outside of the range 0 to 1", p)
+        }
+        InvalidProbabilities(total: f64) {
+            display("drop probabilities must each be between 0 and 1 and sum to 1, got a total of {}", total)
+        }
+        InvalidEarlyStop(probability: f64) {
+            display("early stop probability {} is outside of the range 0 to 1", probability)
+        }
+        InvalidDropChance(chance: f64) {
+            display("drop chance {} is outside of the range 0 (exclusive) to 1", chance)
+        }
+        InvalidConfidence(confidence: f64) {
+            display("confidence {} is not strictly between 0 and 1", confidence)
+        }
+        InvalidDropList(reason: &'static str) {
+            display("invalid drop list: {}", reason)
+        }
+        InvalidStreamWeights(err: rand::distributions::WeightedError) {
+            source(err)
+            display("invalid stream weights: {}", err)
+        }
+        TotalOverflow(total: &'static str) {
+            display("the total {} is too large to fit in a u32", total)
+        }
+        MismatchedGoals(runs: usize, goals: usize) {
+            display("a stream has {} runs but {} run goals", runs, goals)
+        }
+        MismatchedStreamWeights(weights: usize, streams: usize) {
+            display("there are {} stream weights but {} streams", weights, streams)
+        }
+        NoWorkers {
+            display("the simulation has no worker threads")
+        }
+        ThreadSpawnFailed(err: std::io::Error) {
+            source(err)
+            display("failed to spawn a simulation worker thread: {}", err)
+        }
+        Io(err: std::io::Error) {
+            from()
+            source(err)
+            display("io error: {}", err)
+        }
+        Csv(err: csv::Error) {
+            from()
+            source(err)
+            display("csv error: {}", err)
+        }
+        Json(err: serde_json::Error) {
+            from()
+            source(err)
+            display("json error: {}", err)
+        }
     }
 }
@@ -1,12 +1,39 @@
-use crate::drop::{Drop, DropSim, Item};
+use crate::drop::{Drop, DropConfig, DropSim, Item};
+use crate::error::McSimError;
+use rand::Rng;
+use std::convert::TryFrom;
+
+/// The list of drops from bartering or fighting in a run.
+/// With the `smallvec` feature, typical runs keep their drops inline rather than allocating.
+#[cfg(feature = "smallvec")]
+pub type Drops = smallvec::SmallVec<[Drop; 32]>;
+
+/// The list of drops from bartering or fighting in a run.
+/// With the `smallvec` feature, typical runs keep their drops inline rather than allocating.
+#[cfg(not(feature = "smallvec"))]
+pub type Drops = Vec<Drop>;
+
+/// The number of gold ingots that a piglin takes for each barter.
+pub const GOLD_PER_BARTER: u32 = 1;
+
+/// Something done during a run that produces a drop.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Activity {
+    Barter,
+    Fight,
+}
 
 /// Represents a single speed run, in which barters are made and blazes are fought.
 /// The results of bartering and fighting are stored as a list of drops that can be interrogated
 /// to see exactly how lucky or unlucky the run was.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct Run {
-    pub barters: Vec<Drop>,
-    pub fights: Vec<Drop>,
+    pub barters: Drops,
+    pub fights: Drops,
+    /// The order that the barters and fights were made in, if they were interleaved.
+    /// `None` means that all of the barters were made before any of the fights.
+    #[serde(default)]
+    pub sequence: Option<Vec<Activity>>,
 }
 
 impl Run {
@@ -38,8 +65,12 @@ impl Run {
     /// assert_eq!(run.total_fights(), 7);
     /// assert_eq!(run.total_rods(), 3);
     /// ```
-    pub fn new(barters: Vec<Drop>, fights: Vec<Drop>) -> Self {
-        Self { barters, fights }
+    pub fn new(barters: impl Into<Drops>, fights: impl Into<Drops>) -> Self {
+        Self {
+            barters: barters.into(),
+            fights: fights.into(),
+            sequence: None,
+        }
     }
 
     /// The total number of barters that were made in the run.
@@ -47,6 +78,23 @@ impl Run {
         self.barters.len() as u32
     }
 
+    /// The number of gold ingots spent on barters in the run. See: [GOLD_PER_BARTER]
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::run::*;
+    /// let barters = vec![
+    ///     Drop { item: Item::Gravel, roll: 0, count: 8 },
+    ///     Drop { item: Item::EnderPearl, roll: 0, count: 4 },
+    /// ];
+    ///
+    /// let run = Run::new(barters, vec![]);
+    /// assert_eq!(run.gold_spent(), 2);
+    /// ```
+    pub fn gold_spent(&self) -> u32 {
+        self.total_barters() * GOLD_PER_BARTER
+    }
+
+    /// The number of barters in the run that dropped ender pearls.
     pub fn successful_barters(&self) -> u32 {
         self.barters
             .iter()
@@ -63,15 +111,48 @@ impl Run {
             .sum()
     }
 
+    /// The number of pearls obtained beyond a target, since the final barter can drop more than were needed.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::run::*;
+    /// let barters = vec![
+    ///     Drop { item: Item::EnderPearl, roll: 0, count: 6 },
+    ///     Drop { item: Item::EnderPearl, roll: 0, count: 8 },
+    /// ];
+    /// let fights = vec![Drop { item: Item::BlazeRod, roll: 0, count: 1 }];
+    ///
+    /// let run = Run::new(barters, fights);
+    /// assert_eq!(run.pearl_overshoot(10), 4);
+    /// assert_eq!(run.pearl_overshoot(20), 0);
+    /// assert_eq!(run.rod_overshoot(1), 0);
+    /// ```
+    pub fn pearl_overshoot(&self, target: u32) -> u32 {
+        self.total_pearls().saturating_sub(target)
+    }
+
     /// The total number of blazes that were killed in the run.
     pub fn total_fights(&self) -> u32 {
         self.fights.len() as u32
     }
 
+    /// The number of blaze fights in the run that dropped a blaze rod.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::run::*;
+    /// let fights = vec![
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 0 },
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 1 },
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 0 },
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 1 },
+    /// ];
+    ///
+    /// let run = Run::new(vec![], fights);
+    /// assert_eq!(run.successful_fights(), 2);
+    /// ```
     pub fn successful_fights(&self) -> u32 {
-        self.barters
+        self.fights
             .iter()
-            .filter(|drop| drop.item == Item::BlazeRod)
+            .filter(|drop| drop.item == Item::BlazeRod && drop.count > 0)
             .count() as u32
     }
 
@@ -83,6 +164,119 @@ impl Run {
             .map(|drop| drop.count)
             .sum()
     }
+
+    /// The number of blaze rods obtained beyond a target. See: [pearl_overshoot](Run::pearl_overshoot)
+    pub fn rod_overshoot(&self, target: u32) -> u32 {
+        self.total_rods().saturating_sub(target)
+    }
+
+    /// The total count of an item that was obtained during the run, from both barters and fights.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::run::*;
+    /// let barters = vec![
+    ///     Drop { item: Item::Gravel, roll: 0, count: 8 },
+    ///     Drop { item: Item::Quartz, roll: 0, count: 5 },
+    ///     Drop { item: Item::Gravel, roll: 0, count: 12 },
+    ///     Drop { item: Item::EnderPearl, roll: 0, count: 4 },
+    /// ];
+    /// let fights = vec![Drop { item: Item::BlazeRod, roll: 0, count: 1 }];
+    ///
+    /// let run = Run::new(barters, fights);
+    /// assert_eq!(run.item_count(Item::Gravel), 20);
+    /// assert_eq!(run.item_count(Item::Quartz), 5);
+    /// assert_eq!(run.item_count(Item::EnderPearl), run.total_pearls());
+    /// assert_eq!(run.item_count(Item::Obsidian), 0);
+    /// ```
+    pub fn item_count(&self, item: Item) -> u32 {
+        self.drops_of(item).map(|drop| drop.count).sum()
+    }
+
+    /// The number of drops of an item during the run, from both barters and fights, regardless of their counts.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::run::*;
+    /// let barters = vec![
+    ///     Drop { item: Item::Gravel, roll: 0, count: 8 },
+    ///     Drop { item: Item::Quartz, roll: 0, count: 5 },
+    ///     Drop { item: Item::Gravel, roll: 0, count: 12 },
+    /// ];
+    /// let fights = vec![
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 0 },
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 1 },
+    /// ];
+    ///
+    /// let run = Run::new(barters, fights);
+    /// assert_eq!(run.drop_count(Item::Gravel), 2);
+    /// assert_eq!(run.drop_count(Item::Quartz), 1);
+    /// assert_eq!(run.drop_count(Item::BlazeRod), run.total_fights());
+    /// ```
+    pub fn drop_count(&self, item: Item) -> u32 {
+        self.drops_of(item).count() as u32
+    }
+
+    /// The drops of an item during the run, with the barters before the fights.
+    fn drops_of(&self, item: Item) -> impl Iterator<Item = &Drop> {
+        self.barters
+            .iter()
+            .chain(self.fights.iter())
+            .filter(move |drop| drop.item == item)
+    }
+
+    /// The probability of getting exactly the drops in this run, in order, from the barter and blaze drop lists.
+    /// Each drop's probability is that of its item being picked (its weight over the total weight) times the
+    /// probability of its count, so a drop that isn't possible from its drop list makes the run impossible.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// let barters = vec![
+    ///     Drop { item: Item::Gravel, roll: 0, count: 8 },
+    ///     Drop { item: Item::EnderPearl, roll: 0, count: 4 },
+    /// ];
+    ///
+    /// let fights = vec![
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 0 },
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 1 },
+    /// ];
+    ///
+    /// let run = Run::new(barters, fights);
+    /// let barter_list = drop_list::barter_drop_list(10, 10);
+    /// let blaze_list = drop_list::blaze_drop_list(7);
+    ///
+    /// // Gravel is weighted 40 of 423 and drops 8 to 16, pearls are 20 of 423 and drop 4 to 8, and rods are 0 or 1.
+    /// let expected = (40.0 / 423.0 / 9.0) * (20.0 / 423.0 / 5.0) * 0.5 * 0.5;
+    /// assert!((run.probability(barter_list.list(), blaze_list.list()) - expected).abs() < 1e-15);
+    ///
+    /// let impossible = Run::new(vec![Drop { item: Item::EnderPearl, roll: 0, count: 16 }], vec![]);
+    /// assert_eq!(impossible.probability(barter_list.list(), blaze_list.list()), 0.0);
+    /// ```
+    pub fn probability(&self, barter_list: &[DropConfig], blaze_list: &[DropConfig]) -> f64 {
+        drops_probability(&self.barters, barter_list) * drops_probability(&self.fights, blaze_list)
+    }
+}
+
+/// The probability of getting exactly a sequence of drops from a drop list.
+fn drops_probability(drops: &[Drop], drop_list: &[DropConfig]) -> f64 {
+    let total_weight: u32 = drop_list.iter().map(|config| config.weight).sum();
+
+    drops
+        .iter()
+        .map(|drop| {
+            drop_list
+                .iter()
+                .filter(|config| config.item == drop.item)
+                .map(|config| {
+                    config.probability(total_weight)
+                        * config.count_dist.probability(
+                            drop.count,
+                            config.min_count,
+                            config.max_count,
+                        )
+                })
+                .sum::<f64>()
+        })
+        .product()
 }
 
 /// The goals of a run simulation.
@@ -93,11 +287,229 @@ impl Run {
 /// before reaching their goal because the run won't pb if they have to trade any more and they just hope
 /// that they get good portal luck.
 ///
-/// Ideas like this are not in scope for this simulation and can be accounted for in the analysis of the data.
-#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+/// Ideas like this are mostly not in scope for this simulation and can be accounted for in the analysis of the data.
+/// The exception is a runner who leaves the piglins once they judge that they have "enough" pearls,
+/// which can be simulated with an early stop. See: [with_early_stop](RunGoals::with_early_stop)
+///
+/// A target of zero means that the run doesn't farm for that item at all, and the stream results
+/// treat that dimension as neither lucky nor unlucky. See: [pearl_luck](crate::stream::StreamResults::pearl_luck)
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
 pub struct RunGoals {
     pub target_pearls: u32,
     pub target_rods: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    target_successful_barters: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    early_stop: Option<EarlyStop>,
+}
+
+impl RunGoals {
+    /// Creates goals for a run that barters for ender pearls and fights blazes for blaze rods.
+    /// ```
+    /// # use mc_sim::run::*;
+    /// let goals = RunGoals::new(10, 7);
+    /// assert_eq!(goals.target_pearls, 10);
+    /// assert_eq!(goals.target_rods, 7);
+    /// assert_eq!(goals.barter_target(), BarterTarget::Pearls(10));
+    /// ```
+    pub fn new(target_pearls: u32, target_rods: u32) -> Self {
+        Self {
+            target_pearls,
+            target_rods,
+            target_successful_barters: None,
+            early_stop: None,
+        }
+    }
+
+    /// Creates goals for a run that only barters for ender pearls.
+    /// ```
+    /// # use mc_sim::run::*;
+    /// let goals = RunGoals::pearls_only(10);
+    /// assert_eq!(goals.target_pearls, 10);
+    /// assert_eq!(goals.target_rods, 0);
+    /// ```
+    pub fn pearls_only(target_pearls: u32) -> Self {
+        RunGoals::new(target_pearls, 0)
+    }
+
+    /// Creates goals for a run that only fights blazes for blaze rods.
+    pub fn rods_only(target_rods: u32) -> Self {
+        RunGoals::new(0, target_rods)
+    }
+
+    /// Sets the number of barters that must drop ender pearls before the run stops bartering,
+    /// in place of the pearl target. See: [target_successful_barters](RunGoals::target_successful_barters)
+    /// ```
+    /// # use mc_sim::drop::DropSim;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// # use mc_sim::stream::Stream;
+    /// let goals = RunGoals::new(10, 7).with_successful_barters(3);
+    /// assert_eq!(goals.target_successful_barters(), Some(3));
+    /// assert_eq!(goals.barter_target(), BarterTarget::SuccessfulBarters(3));
+    ///
+    /// let mut barter_drop_sim = DropSim::new(drop_list::barter_drop_list(20, 10).list_clone());
+    /// let mut blaze_drop_sim = DropSim::new(drop_list::blaze_drop_list(14).list_clone());
+    /// let stream = Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, vec![goals; 2]);
+    /// assert_eq!(stream.successful_barters(), 6);
+    /// ```
+    pub fn with_successful_barters(mut self, target_successful_barters: u32) -> Self {
+        self.target_successful_barters = Some(target_successful_barters);
+        self
+    }
+
+    /// Gives the run a chance to stop bartering early, once it has at least a soft target of pearls.
+    /// After each barter that drops pearls past the soft target, the run stops with the given probability,
+    /// even if it hasn't reached its barter target yet.
+    /// ```
+    /// # use mc_sim::drop::DropSim;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// # use mc_sim::stream::Stream;
+    /// let mut barter_drop_sim = DropSim::new(drop_list::barter_drop_list(10, 10).list_clone());
+    /// let mut blaze_drop_sim = DropSim::new(drop_list::blaze_drop_list(7).list_clone());
+    ///
+    /// let goals = RunGoals::new(10, 7).with_early_stop(6, 0.5);
+    /// assert_eq!(goals.early_stop().unwrap().probability(), 0.5);
+    /// let stream = Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, vec![goals; 200]);
+    ///
+    /// // Some runs stopped short of 10 pearls, but none of them before the soft target of 6.
+    /// let pearls: Vec<u32> = stream.runs.iter().map(|run| run.total_pearls()).collect();
+    /// assert!(pearls.iter().any(|&p| p < 10));
+    /// assert!(pearls.iter().all(|&p| p >= 6));
+    ///
+    /// let stream = Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, vec![RunGoals::new(10, 7); 200]);
+    /// assert!(stream.runs.iter().all(|run| run.total_pearls() >= 10));
+    /// ```
+    ///
+    /// # Panics
+    /// If the probability isn't between 0 and 1.
+    pub fn with_early_stop(mut self, soft_target_pearls: u32, probability: f64) -> Self {
+        self.early_stop = Some(
+            EarlyStop::new(soft_target_pearls, probability)
+                .expect("the early stop probability must be between 0 and 1"),
+        );
+        self
+    }
+
+    /// The chance for the run to stop bartering before it reaches its target, if it has one. See: [EarlyStop]
+    pub fn early_stop(&self) -> Option<EarlyStop> {
+        self.early_stop
+    }
+
+    /// The number of barters that must drop ender pearls before the run stops bartering, regardless of how many
+    /// pearls they dropped. When this is set, it replaces the pearl target in simulating the run, but the pearl target
+    /// is still what the stream results and their luck are measured against. See: [barter_target](RunGoals::barter_target)
+    pub fn target_successful_barters(&self) -> Option<u32> {
+        self.target_successful_barters
+    }
+
+    /// What the run barters until.
+    pub fn barter_target(&self) -> BarterTarget {
+        match self.target_successful_barters {
+            Some(successful_barters) => BarterTarget::SuccessfulBarters(successful_barters),
+            None => BarterTarget::Pearls(self.target_pearls),
+        }
+    }
+}
+
+/// What a run barters with piglins until.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub enum BarterTarget {
+    /// Barter until at least this many ender pearls have dropped.
+    Pearls(u32),
+    /// Barter until exactly this many barters have dropped ender pearls, however many pearls each of them dropped.
+    /// This is the number of successes in the negative binomial model of an
+    /// [EnderPearlDistribution](crate::stats::EnderPearlDistribution).
+    SuccessfulBarters(u32),
+}
+
+impl BarterTarget {
+    /// Whether the target has been reached, given the pearls and successful barters so far.
+    /// ```
+    /// # use mc_sim::run::*;
+    /// assert!(BarterTarget::Pearls(10).reached(12, 2));
+    /// assert!(!BarterTarget::Pearls(10).reached(8, 2));
+    /// assert!(BarterTarget::SuccessfulBarters(3).reached(12, 3));
+    /// assert!(!BarterTarget::SuccessfulBarters(3).reached(16, 2));
+    /// ```
+    pub fn reached(&self, pearls: u32, successful_barters: u32) -> bool {
+        match *self {
+            BarterTarget::Pearls(target) => pearls >= target,
+            BarterTarget::SuccessfulBarters(target) => successful_barters >= target,
+        }
+    }
+}
+
+/// A chance for a run to stop bartering before its barter target, modelling a runner who leaves the piglins
+/// once they judge that they have enough pearls. See: [with_early_stop](RunGoals::with_early_stop)
+///
+/// The probability is checked when an early stop is created or deserialized.
+/// ```
+/// # use mc_sim::run::*;
+/// let goals: RunGoals = serde_json::from_str(
+///     r#"{ "target_pearls": 10, "target_rods": 7, "early_stop": { "soft_target_pearls": 6, "probability": 0.5 } }"#,
+/// ).unwrap();
+/// assert_eq!(goals, RunGoals::new(10, 7).with_early_stop(6, 0.5));
+///
+/// assert!(EarlyStop::new(6, 1.5).is_err());
+/// assert!(serde_json::from_str::<RunGoals>(
+///     r#"{ "target_pearls": 10, "target_rods": 7, "early_stop": { "soft_target_pearls": 6, "probability": 1.5 } }"#,
+/// ).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+#[serde(try_from = "UncheckedEarlyStop")]
+pub struct EarlyStop {
+    soft_target_pearls: u32,
+    probability: f64,
+}
+
+// The probability is never NaN, since it is checked whenever an early stop is created.
+impl Eq for EarlyStop {}
+
+/// An early stop as it is deserialized, before its probability is checked.
+#[derive(Deserialize)]
+struct UncheckedEarlyStop {
+    soft_target_pearls: u32,
+    probability: f64,
+}
+
+impl TryFrom<UncheckedEarlyStop> for EarlyStop {
+    type Error = McSimError;
+
+    fn try_from(unchecked: UncheckedEarlyStop) -> Result<Self, Self::Error> {
+        EarlyStop::new(unchecked.soft_target_pearls, unchecked.probability)
+    }
+}
+
+impl EarlyStop {
+    /// Creates an early stop, or an error if the probability isn't between 0 and 1.
+    pub fn new(soft_target_pearls: u32, probability: f64) -> Result<Self, McSimError> {
+        if !(0.0..=1.0).contains(&probability) {
+            return Err(McSimError::InvalidEarlyStop(probability));
+        }
+
+        Ok(Self {
+            soft_target_pearls,
+            probability,
+        })
+    }
+
+    /// The number of pearls from which the run may stop early.
+    pub fn soft_target_pearls(&self) -> u32 {
+        self.soft_target_pearls
+    }
+
+    /// The probability of stopping after each barter that drops pearls, once the soft target has been reached.
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// Rolls for whether a run with this many pearls stops after a barter that dropped pearls.
+    /// Only rolls once the soft target is reached, so that the drop simulator is otherwise left as it is.
+    fn stops(&self, drop_sim: &mut DropSim, pearls: u32) -> bool {
+        pearls >= self.soft_target_pearls && drop_sim.rng_mut().gen_bool(self.probability)
+    }
 }
 
 /// A Minecraft speed run simulation.
@@ -105,7 +517,8 @@ pub struct RunGoals {
 pub struct RunSim<'a, 'b> {
     barter_drop_sim: &'a mut DropSim,
     blaze_drop_sim: &'b mut DropSim,
-    pearl_target: u32,
+    barter_target: BarterTarget,
+    early_stop: Option<EarlyStop>,
     rods_target: u32,
 }
 
@@ -132,33 +545,283 @@ impl<'a, 'b> RunSim<'a, 'b> {
         Self {
             barter_drop_sim,
             blaze_drop_sim,
-            pearl_target,
+            barter_target: BarterTarget::Pearls(pearl_target),
+            early_stop: None,
             rods_target,
         }
     }
 
+    /// Sets what the run barters until, in place of the pearl target.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// let mut barter_drop_sim = DropSim::new(drop_list::barter_drop_list(10, 10).list_clone());
+    /// let mut blaze_drop_sim = DropSim::new(drop_list::blaze_drop_list(7).list_clone());
+    ///
+    /// for _ in 0..100 {
+    ///     let run = RunSim::new(&mut barter_drop_sim, &mut blaze_drop_sim, 10, 7)
+    ///         .with_barter_target(BarterTarget::SuccessfulBarters(3))
+    ///         .run();
+    ///
+    ///     assert_eq!(run.drop_count(Item::EnderPearl), 3);
+    ///     assert_eq!(run.barters.last().unwrap().item, Item::EnderPearl);
+    /// }
+    /// ```
+    pub fn with_barter_target(mut self, barter_target: BarterTarget) -> Self {
+        self.barter_target = barter_target;
+        self
+    }
+
+    /// Sets the chance for the run to stop bartering early, if it has one. See: [with_early_stop](RunGoals::with_early_stop)
+    /// Runs simulated with [run_exact](RunSim::run_exact) always barter until their target.
+    pub fn with_early_stop(mut self, early_stop: Option<EarlyStop>) -> Self {
+        self.early_stop = early_stop;
+        self
+    }
+
     /// Simulate a run.
     pub fn run(&mut self) -> Run {
         Run::new(self.barter_for_pearls(), self.fight_for_rods())
     }
 
-    /// Barter for pearls until the pearl target is reached.
-    pub fn barter_for_pearls(&mut self) -> Vec<Drop> {
-        RunSim::farm_for_item(
-            &mut self.barter_drop_sim,
-            Item::EnderPearl,
-            self.pearl_target,
+    /// Simulate a run like [run](RunSim::run), but into an existing run, which is cleared first.
+    /// This reuses the run's lists of drops, so simulating many runs into the same one avoids allocating for each.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// let barter_list = drop_list::barter_drop_list(10, 10).list_clone();
+    /// let blaze_list = drop_list::blaze_drop_list(7).list_clone();
+    /// let mut barter_drop_sim = DropSim::from_seed(barter_list.clone(), 5);
+    /// let mut blaze_drop_sim = DropSim::from_seed(blaze_list.clone(), 6);
+    /// let mut fresh_barter_drop_sim = DropSim::from_seed(barter_list, 5);
+    /// let mut fresh_blaze_drop_sim = DropSim::from_seed(blaze_list, 6);
+    ///
+    /// let mut run = Run::new(vec![], vec![]);
+    /// for _ in 0..100 {
+    ///     RunSim::new(&mut barter_drop_sim, &mut blaze_drop_sim, 10, 7).run_into(&mut run);
+    ///     let fresh = RunSim::new(&mut fresh_barter_drop_sim, &mut fresh_blaze_drop_sim, 10, 7).run();
+    ///
+    ///     assert_eq!(run.total_barters(), fresh.total_barters());
+    ///     assert_eq!(run.total_pearls(), fresh.total_pearls());
+    ///     assert_eq!(run.total_fights(), fresh.total_fights());
+    ///     assert_eq!(run.total_rods(), fresh.total_rods());
+    ///     assert_eq!(run, fresh);
+    /// }
+    /// ```
+    pub fn run_into(&mut self, run: &mut Run) {
+        run.sequence = None;
+        RunSim::barter_into(
+            self.barter_drop_sim,
+            self.barter_target,
+            self.early_stop,
+            &mut run.barters,
+        );
+        RunSim::farm_for_item_into(
+            self.blaze_drop_sim,
+            Item::BlazeRod,
+            self.rods_target,
+            &mut run.fights,
+        );
+    }
+
+    /// Simulate a run where the final drop of each target item is clamped, so that the run gets exactly
+    /// the target number of pearls and rods rather than overshooting them.
+    /// A target of successful barters is always reached exactly, so those barters are left as they are.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// let mut barter_drop_sim = DropSim::new(drop_list::barter_drop_list(10, 10).list_clone());
+    /// let mut blaze_drop_sim = DropSim::new(drop_list::blaze_drop_list(7).list_clone());
+    ///
+    /// for _ in 0..100 {
+    ///     let mut run_sim = RunSim::new(&mut barter_drop_sim, &mut blaze_drop_sim, 10, 7);
+    ///     let run = run_sim.run_exact();
+    ///     assert_eq!(run.total_pearls(), 10);
+    ///     assert_eq!(run.total_rods(), 7);
+    /// }
+    /// ```
+    pub fn run_exact(&mut self) -> Run {
+        let barters = match self.barter_target {
+            BarterTarget::Pearls(target) => {
+                RunSim::farm_for_item_exact(self.barter_drop_sim, Item::EnderPearl, target)
+            }
+            BarterTarget::SuccessfulBarters(_) => self.barter_for_pearls(),
+        };
+
+        Run::new(
+            barters,
+            RunSim::farm_for_item_exact(self.blaze_drop_sim, Item::BlazeRod, self.rods_target),
         )
     }
 
+    /// Simulate a run that interleaves barters and fights, rather than making all of the barters first.
+    /// The ratio is the number of barters made per blaze fought, while neither target has been reached.
+    /// Once one target is reached, only the other is farmed for.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// let mut barter_drop_sim = DropSim::new(drop_list::barter_drop_list(10, 10).list_clone());
+    /// let mut blaze_drop_sim = DropSim::new(drop_list::blaze_drop_list(7).list_clone());
+    ///
+    /// let mut run_sim = RunSim::new(&mut barter_drop_sim, &mut blaze_drop_sim, 10, 7);
+    /// let run = run_sim.run_interleaved(2.0);
+    /// assert!(run.total_pearls() >= 10);
+    /// assert!(run.total_rods() >= 7);
+    ///
+    /// let sequence = run.sequence.as_ref().unwrap();
+    /// assert_eq!(sequence.len() as u32, run.total_barters() + run.total_fights());
+    /// assert_eq!(&sequence[..3], &[Activity::Barter, Activity::Barter, Activity::Fight]);
+    /// ```
+    pub fn run_interleaved(&mut self, ratio: f64) -> Run {
+        let (mut barters, mut fights) = (Drops::new(), Drops::new());
+        let (mut pearls, mut successful_barters, mut rods) = (0, 0, 0);
+        let mut sequence = Vec::new();
+        let mut stopped_early = false;
+
+        loop {
+            let bartered = stopped_early || self.barter_target.reached(pearls, successful_barters);
+            if bartered && rods >= self.rods_target {
+                break;
+            }
+
+            let barter = if bartered {
+                false
+            } else if rods >= self.rods_target {
+                true
+            } else {
+                (barters.len() as f64) < ratio * (fights.len() + 1) as f64
+            };
+
+            if barter {
+                let drop = self.barter_drop_sim.get_drop();
+                if drop.item == Item::EnderPearl {
+                    pearls += drop.count;
+                    successful_barters += 1;
+                    stopped_early = !self.barter_target.reached(pearls, successful_barters)
+                        && self.early_stop.is_some_and(|early_stop| {
+                            early_stop.stops(self.barter_drop_sim, pearls)
+                        });
+                }
+                barters.push(drop);
+                sequence.push(Activity::Barter);
+            } else {
+                let drop = self.blaze_drop_sim.get_drop();
+                if drop.item == Item::BlazeRod {
+                    rods += drop.count;
+                }
+                fights.push(drop);
+                sequence.push(Activity::Fight);
+            }
+        }
+
+        Run {
+            sequence: Some(sequence),
+            ..Run::new(barters, fights)
+        }
+    }
+
+    /// Barter for pearls until the barter target is reached.
+    pub fn barter_for_pearls(&mut self) -> Drops {
+        let mut drops = Drops::new();
+        RunSim::barter_into(
+            self.barter_drop_sim,
+            self.barter_target,
+            self.early_stop,
+            &mut drops,
+        );
+        drops
+    }
+
     /// Fight blazes until the rod target is reached.
-    pub fn fight_for_rods(&mut self) -> Vec<Drop> {
-        RunSim::farm_for_item(&mut self.blaze_drop_sim, Item::BlazeRod, self.rods_target)
+    pub fn fight_for_rods(&mut self) -> Drops {
+        RunSim::farm_for_item(self.blaze_drop_sim, Item::BlazeRod, self.rods_target)
     }
 
     /// Farm for an item from a drop simulator with a minimum target before we're done.
-    pub fn farm_for_item(drop_sim: &mut DropSim, item: Item, minimum: u32) -> Vec<Drop> {
-        let mut drops = Vec::new();
+    pub fn farm_for_item(drop_sim: &mut DropSim, item: Item, minimum: u32) -> Drops {
+        let mut drops = Drops::new();
+        RunSim::farm_for_item_into(drop_sim, item, minimum, &mut drops);
+        drops
+    }
+
+    /// Farm for an item like [farm_for_item](RunSim::farm_for_item), but into an existing list of drops,
+    /// which is cleared first.
+    pub fn farm_for_item_into(drop_sim: &mut DropSim, item: Item, minimum: u32, drops: &mut Drops) {
+        drops.clear();
+
+        if minimum > 0 {
+            drops.extend(drop_sim.drops_until(|count, drop| {
+                if drop.item == item {
+                    *count += drop.count;
+                }
+
+                *count >= minimum
+            }));
+        }
+    }
+
+    /// Barter with piglins until a barter target is reached, into an existing list of drops, which is cleared first.
+    /// A target of pearls barters exactly like [farm_for_item_into](RunSim::farm_for_item_into).
+    pub fn barter_for_target_into(drop_sim: &mut DropSim, target: BarterTarget, drops: &mut Drops) {
+        RunSim::barter_into(drop_sim, target, None, drops);
+    }
+
+    /// Barter with piglins until a barter target is reached, or the run stops early, into an existing list of drops.
+    fn barter_into(
+        drop_sim: &mut DropSim,
+        target: BarterTarget,
+        early_stop: Option<EarlyStop>,
+        drops: &mut Drops,
+    ) {
+        drops.clear();
+        let (mut pearls, mut successful_barters) = (0, 0);
+
+        while !target.reached(pearls, successful_barters) {
+            let drop = drop_sim.get_drop();
+            let successful = drop.item == Item::EnderPearl;
+
+            if successful {
+                pearls += drop.count;
+                successful_barters += 1;
+            }
+
+            drops.push(drop);
+
+            if successful
+                && !target.reached(pearls, successful_barters)
+                && early_stop.is_some_and(|early_stop| early_stop.stops(drop_sim, pearls))
+            {
+                break;
+            }
+        }
+    }
+
+    /// Farm for an item like [farm_for_item](RunSim::farm_for_item), but clamp the count of the final drop
+    /// of the item so that exactly the target is reached.
+    pub fn farm_for_item_exact(drop_sim: &mut DropSim, item: Item, target: u32) -> Drops {
+        let mut drops = RunSim::farm_for_item(drop_sim, item, target);
+        let overshoot = drops
+            .iter()
+            .filter(|drop| drop.item == item)
+            .map(|drop| drop.count)
+            .sum::<u32>()
+            .saturating_sub(target);
+
+        if let Some(last) = drops.iter_mut().rev().find(|drop| drop.item == item) {
+            last.count -= overshoot;
+        }
+
+        drops
+    }
+
+    /// Farm for an item like [farm_for_item](RunSim::farm_for_item), but only count the drops rather than keeping them.
+    /// Returns the total number of drops, the number of those drops that were the item, and the total count of the item.
+    pub fn tally_for_item(drop_sim: &mut DropSim, item: Item, minimum: u32) -> (u32, u32, u32) {
+        let (mut total, mut successful) = (0, 0);
         let mut count = 0;
 
         while count < minimum {
@@ -166,11 +829,63 @@ impl<'a, 'b> RunSim<'a, 'b> {
 
             if drop.item == item {
                 count += drop.count;
+
+                if drop.count > 0 {
+                    successful += 1;
+                }
             }
 
-            drops.push(drop);
+            total += 1;
         }
 
-        drops
+        (total, successful, count)
+    }
+
+    /// Barter like [barter_for_target_into](RunSim::barter_for_target_into), but only count the drops rather than
+    /// keeping them. Returns the same totals as [tally_for_item](RunSim::tally_for_item).
+    pub fn tally_for_barter_target(
+        drop_sim: &mut DropSim,
+        target: BarterTarget,
+    ) -> (u32, u32, u32) {
+        match target {
+            BarterTarget::Pearls(target) => {
+                RunSim::tally_for_item(drop_sim, Item::EnderPearl, target)
+            }
+            BarterTarget::SuccessfulBarters(_) => RunSim::tally_barters(drop_sim, target, None),
+        }
+    }
+
+    /// Barter like [tally_for_barter_target](RunSim::tally_for_barter_target), for the barter target of a run's goals,
+    /// along with their chance to stop early. See: [with_early_stop](RunGoals::with_early_stop)
+    pub fn tally_for_goals(drop_sim: &mut DropSim, goals: &RunGoals) -> (u32, u32, u32) {
+        RunSim::tally_barters(drop_sim, goals.barter_target(), goals.early_stop())
+    }
+
+    /// Counts barters until a barter target is reached, or the run stops early.
+    fn tally_barters(
+        drop_sim: &mut DropSim,
+        target: BarterTarget,
+        early_stop: Option<EarlyStop>,
+    ) -> (u32, u32, u32) {
+        let (mut total, mut successful) = (0, 0);
+        let mut count = 0;
+
+        while !target.reached(count, successful) {
+            let drop = drop_sim.get_drop();
+            total += 1;
+
+            if drop.item == Item::EnderPearl {
+                count += drop.count;
+                successful += 1;
+
+                if !target.reached(count, successful)
+                    && early_stop.is_some_and(|early_stop| early_stop.stops(drop_sim, count))
+                {
+                    break;
+                }
+            }
+        }
+
+        (total, successful, count)
     }
 }
@@ -1,35 +1,121 @@
-use crate::drop::DropSim;
 use crate::drop_list::{self, DropList};
+use crate::error::McSimError;
 use crate::run::RunGoals;
 use crate::stats::{BlazeRodDistribution, EnderPearlDistribution};
-use crate::stream::{Stream, StreamResults};
-use std::sync::{Arc, RwLock, RwLockReadGuard};
-use std::thread;
-use std::{thread::JoinHandle, time::Instant};
+use crate::stream::StreamResults;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "threaded")]
+mod threaded;
+
+#[cfg(feature = "threaded")]
+pub use threaded::*;
 
 /// The goals of a simulation of speed run streams.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SimulationGoals {
     pub streams: Vec<Vec<RunGoals>>,
+    /// How often each stream is simulated relative to the others. Streams without a weight have a weight of 1.0.
+    /// See: [with_weights](SimulationGoals::with_weights)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub weights: Vec<f64>,
 }
 
 impl SimulationGoals {
     /// Create simulation goals from a list of streams.
     pub fn new(streams: Vec<Vec<RunGoals>>) -> Self {
-        Self { streams }
+        Self {
+            streams,
+            weights: Vec::new(),
+        }
     }
 
     /// Create simulation goals for a number of streams that repeat a set of runs a specific number of times.
     pub fn new_repeat_streams(streams: u64, run_goals: Vec<RunGoals>) -> Self {
-        Self {
-            streams: (0..streams).map(|_| run_goals.clone()).collect(),
-        }
+        Self::new((0..streams).map(|_| run_goals.clone()).collect())
+    }
+
+    /// Create the simulation goals of Dream's stream: 22 runs, each targeting 10 ender pearls and 7 blaze rods.
+    /// See: [dream_luck](crate::stats::dream_luck)
+    /// ```
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoals::dream_scenario();
+    /// assert_eq!(goals.streams.len(), 1);
+    /// assert_eq!(goals.streams[0].len(), 22);
+    /// assert!(goals.streams[0].iter().all(|run| *run == RunGoals::new(10, 7)));
+    /// ```
+    pub fn dream_scenario() -> Self {
+        Self::new_repeat_streams(1, vec![RunGoals::new(10, 7); 22])
+    }
+
+    /// Weight the streams, so that some are simulated more often than others.
+    /// Each cycle still simulates as many streams as there are in the goals, but when the weights differ,
+    /// each of those is sampled in proportion to the weights rather than every stream being simulated once.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new()
+    ///     .add_runs(1, 10, 7)
+    ///     .add_stream()
+    ///     .add_runs(2, 10, 7)
+    ///     .goals()
+    ///     .with_weights(vec![20.0, 1.0]);
+    /// assert_eq!(goals.weight(1), 1.0);
+    ///
+    /// #[cfg(feature = "threaded")]
+    /// {
+    ///     let results = Simulation::new(goals, 4).simulate_n_times(500);
+    ///     let heavy = results.iter().filter(|r| r.number_of_runs == 1).count();
+    ///     assert!(heavy > 10 * (results.len() - heavy));
+    ///
+    ///     let goals = SimulationGoalsBuilder::new().add_run(10, 7).goals().with_weights(vec![-1.0]);
+    ///     assert!(Simulation::try_new(goals, 1).is_err());
+    ///
+    ///     // Every stream needs a weight.
+    ///     let goals = SimulationGoalsBuilder::new().add_run(10, 7).goals().with_weights(vec![1.0, 2.0]);
+    ///     assert!(Simulation::try_new(goals, 1).is_err());
+    /// }
+    /// ```
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// The weight of a stream, which is 1.0 unless it has been weighted.
+    pub fn weight(&self, stream: usize) -> f64 {
+        self.weights.get(stream).copied().unwrap_or(1.0)
     }
 
     /// Consume the simulation goals and get out all of the streams run goal lists.
     pub fn into_streams(self) -> Vec<Vec<RunGoals>> {
         self.streams
     }
+
+    /// Load simulation goals from a JSON file.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoals::dream_scenario();
+    /// let path = std::env::temp_dir().join("mc_sim_goals_doctest.json");
+    /// goals.save(&path).unwrap();
+    ///
+    /// let loaded = SimulationGoals::load(&path).unwrap();
+    /// assert_eq!(loaded.streams, goals.streams);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, McSimError> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Save simulation goals to a JSON file. See: [load](SimulationGoals::load)
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), McSimError> {
+        let writer = BufWriter::new(File::create(path)?);
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
 }
 
 /// Builds simulation goals from chain calls, to make simulation goals easier to configure.
@@ -76,390 +162,126 @@ impl SimulationGoalsBuilder {
 
     /// Add a run to the simulation.
     pub fn add_run(mut self, target_pearls: u32, target_rods: u32) -> Self {
-        if self.streams.len() == 0 {
+        if self.streams.is_empty() {
             return self.add_stream().add_run(target_pearls, target_rods);
         }
 
-        self.streams.last_mut().unwrap().push(RunGoals {
-            target_pearls,
-            target_rods,
-        });
+        self.streams
+            .last_mut()
+            .unwrap()
+            .push(RunGoals::new(target_pearls, target_rods));
         self
     }
 
     /// Add a set of runs to the simulation.
     pub fn add_runs(mut self, runs: u32, target_pearls: u32, target_rods: u32) -> Self {
-        if self.streams.len() == 0 {
+        if self.streams.is_empty() {
             return self.add_stream().add_runs(runs, target_pearls, target_rods);
         }
 
         for _ in 0..runs {
-            self.streams.last_mut().unwrap().push(RunGoals {
-                target_pearls,
-                target_rods,
-            });
+            self.streams
+                .last_mut()
+                .unwrap()
+                .push(RunGoals::new(target_pearls, target_rods));
         }
         self
     }
-}
-
-/// A single thread used in simulating minecraft runs.
-/// All the actual work is done on worker threads, not on the main thread.
-struct SimulationThread {
-    luckiest_stream: Arc<RwLock<Option<Stream>>>,
-    simulations: Arc<RwLock<u64>>,
-    thread: JoinHandle<Vec<StreamResults>>,
-}
-
-impl SimulationThread {
-    /// Create a simulation thread.
-    /// The `completed` locked-bool is used to stop the thread.
-    pub fn new(
-        name: String,
-        completed: Arc<RwLock<bool>>,
-        goals: SimulationGoals,
-        barter_drop_list: DropList<EnderPearlDistribution>,
-        blaze_drop_list: DropList<BlazeRodDistribution>,
-    ) -> Self {
-        let luckiest_stream = Arc::new(RwLock::new(None));
-        let simulations = Arc::new(RwLock::new(0));
-
-        Self {
-            luckiest_stream: Arc::clone(&luckiest_stream),
-            simulations: Arc::clone(&simulations),
-            thread: thread::Builder::new()
-                .name(name)
-                .spawn(move || {
-                    SimulationThread::run(
-                        goals,
-                        completed,
-                        luckiest_stream,
-                        simulations,
-                        barter_drop_list,
-                        blaze_drop_list,
-                    )
-                })
-                .unwrap(),
-        }
-    }
-
-    /// The number of simulations that have been completed.
-    /// This is only updated every now and then while running, so it is approximate
-    /// until the thread has been joined.
-    pub fn simulations(&self) -> u64 {
-        *self.simulations.read().unwrap()
-    }
-
-    /// The luckiest stream seen so far by this worker thread.
-    pub fn luckiest_stream(&self) -> RwLockReadGuard<Option<Stream>> {
-        self.luckiest_stream.read().unwrap()
-    }
-
-    /// Consumes the simulation thread into a join handle, which provides the stream results.
-    pub fn into_thread(self) -> JoinHandle<Vec<StreamResults>> {
-        self.thread
-    }
 
-    /// Runs the simulation.
-    fn run(
-        goals: SimulationGoals,
-        completed: Arc<RwLock<bool>>,
-        luckiest_stream: Arc<RwLock<Option<Stream>>>,
-        simulations: Arc<RwLock<u64>>,
-        barter_drop_list: DropList<EnderPearlDistribution>,
-        blaze_drop_list: DropList<BlazeRodDistribution>,
-    ) -> Vec<StreamResults> {
-        // Each thread uses it's own drop simulators so that they keep the RNG on that thread.
-        let mut barter_drop_sim = DropSim::new(barter_drop_list.list_clone());
-        let mut blaze_drop_sim = DropSim::new(blaze_drop_list.list_clone());
-
-        // The results of running a simulation are just simple StreamResults.
-        // The entire streams could be stored and returned, but that would eat memory fast.
-        let mut data = Vec::<StreamResults>::new();
-        let mut tries = 0;
-        let mut last_update = Instant::now();
-
-        // Tracks the best stream so far. Starts as unreasonably bad luck, so that we immediately replace this.
-        let mut personal_best_luck = 1.0;
-        let mut personal_best_barters = 999999;
-        let mut personal_best_fights = 999999;
-
-        loop {
-            // Simulate our list of streams.
-            let streams: Vec<Stream> = goals
-                .clone()
-                .into_streams()
-                .into_iter()
-                .map(|run_goals| {
-                    Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, run_goals)
-                })
-                .collect();
-
-            // Add the data to our results.
-            for stream in streams {
-                let results = stream.results();
-                data.push(results.clone());
-                tries += 1;
-
-                // Does it look like we might have beaten our PB?
-                if personal_best_barters > results.total_barters
-                    || personal_best_fights > results.total_fights
-                {
-                    let luck = results.luck(&barter_drop_list, &blaze_drop_list);
-
-                    // Only actually grab the luckiest stream rwlock when we know we've beaten our PB.
-                    if personal_best_luck > luck {
-                        personal_best_luck = luck;
-                        personal_best_barters = results.total_barters;
-                        personal_best_fights = results.total_fights;
-
-                        *luckiest_stream.write().unwrap() = Some(stream.clone());
-                    }
-                }
-            }
-
-            // Every now and then, update the number of simulations run
-            // and check if we should stop because the completed flag is set.
-            // This is done to avoid hogging the rwlocks.
-            if last_update.elapsed().as_millis() >= 2000 {
-                last_update = Instant::now();
-                *simulations.write().unwrap() = tries;
-
-                if *completed.read().unwrap() {
-                    break;
-                }
+    /// Repeat the most recently added stream a number of times more.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new()
+    ///     .add_run(10, 7)
+    ///     .add_run(12, 6)
+    ///     .repeat_last_stream(999)
+    ///     .goals();
+    ///
+    /// assert_eq!(goals.streams.len(), 1000);
+    /// assert!(goals.streams.iter().all(|stream| *stream == goals.streams[0]));
+    /// ```
+    pub fn repeat_last_stream(mut self, times: u32) -> Self {
+        if let Some(stream) = self.streams.last().cloned() {
+            for _ in 0..times {
+                self.streams.push(stream.clone());
             }
         }
+        self
+    }
+}
 
-        data
+impl Default for SimulationGoalsBuilder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-/// A simulation of a series of streams of speed runs, distributed over worker threads.
-pub struct Simulation {
-    goals: SimulationGoals,
-    completed: Arc<RwLock<bool>>,
-    workers: Vec<SimulationThread>,
-    barter_drop_list: DropList<EnderPearlDistribution>,
-    blaze_drop_list: DropList<BlazeRodDistribution>,
+/// The drop lists used by a simulation, which are shared between its worker threads.
+#[derive(Debug, Clone)]
+pub struct SimulationDropLists {
+    pub barter: DropList<EnderPearlDistribution>,
+    pub blaze: DropList<BlazeRodDistribution>,
 }
 
-impl Simulation {
-    /// Create a simulation.
+impl SimulationDropLists {
+    /// Creates the drop lists for simulation goals, with distributions over the targets of all the streams.
+    /// The pearls targeted per run are worked out for each stream, so streams can have different numbers of runs.
     /// ```
+    /// # use mc_sim::drop::DropSim;
+    /// # use mc_sim::drop_list;
     /// # use mc_sim::sim::*;
-    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
-    /// let simulation = Simulation::new(goals, 4);
-    /// let results = simulation.simulate_n_times(100);
-    /// # assert!(results.len() >= 100);
+    /// # use mc_sim::stream::Stream;
+    /// // One long run for 20 pearls, and a stream of three short runs for 4.
+    /// let goals = SimulationGoalsBuilder::new()
+    ///     .add_run(20, 0)
+    ///     .add_stream()
+    ///     .add_runs(3, 4, 0)
+    ///     .goals();
+    /// let drop_lists = SimulationDropLists::new(&goals);
+    ///
+    /// let mut barter_drop_sim = DropSim::from_seed(drop_lists.barter.list_clone(), 1);
+    /// let mut blaze_drop_sim = DropSim::from_seed(drop_lists.blaze.list_clone(), 2);
+    /// let samples = 5000;
+    /// let mean_failures = (0..samples)
+    ///     .flat_map(|_| goals.streams.iter())
+    ///     .map(|stream| Stream::simulate_results_only(&mut barter_drop_sim, &mut blaze_drop_sim, stream))
+    ///     .map(|results| (results.total_barters - results.successful_barters) as f64)
+    ///     .sum::<f64>() / samples as f64;
+    ///
+    /// // Averaging the target over all of the runs, as if every stream were the same, is 8 pearls per run.
+    /// let averaged = drop_list::barter_drop_list(32, 8).distribution().unwrap();
+    /// let per_stream = drop_lists.barter.distribution().unwrap();
+    /// assert!(
+    ///     (mean_failures - per_stream.expected_failures()).abs()
+    ///         < (mean_failures - averaged.expected_failures()).abs()
+    /// );
     /// ```
-    pub fn new(goals: SimulationGoals, thread_count: u32) -> Self {
-        let completed = Arc::new(RwLock::new(false));
-        let (barter_drop_list, blaze_drop_list) = Simulation::drop_lists(&goals);
-
-        Self {
-            barter_drop_list: barter_drop_list.clone(),
-            blaze_drop_list: blaze_drop_list.clone(),
-            goals: goals.clone(),
-            completed: Arc::clone(&completed),
-            workers: (0..thread_count)
-                .map(|id| {
-                    SimulationThread::new(
-                        format!("Simulation Worker Thread #{}", id),
-                        Arc::clone(&completed),
-                        goals.clone(),
-                        barter_drop_list.clone(),
-                        blaze_drop_list.clone(),
-                    )
-                })
-                .collect(),
-        }
-    }
-
-    /// Run the simulation for a given number of cycles and get the results.
-    /// This will consume the simulator.
-    pub fn simulate_n_times(self, cycles: u64) -> Vec<StreamResults> {
-        let mut last_printed = Instant::now();
-        let start = Instant::now();
-
-        loop {
-            if last_printed.elapsed().as_millis() >= 5000 {
-                last_printed = Instant::now();
-                self.print_update_with_progress(&start, cycles * self.goals.streams.len() as u64);
-
-                if self.simulations() >= cycles {
-                    *self.completed.write().unwrap() = true;
-                    break;
-                }
-            }
-
-            thread::yield_now();
-        }
-
-        self.into_results()
-    }
-
-    /// Run the simulation until a desired p-value is reached.
-    /// I.E. The luckiest run seen, is as lucky, or luckier than the given p-value.
-    pub fn run_to_p_value(self, p_value: f64) -> StreamResults {
-        let mut last_printed = Instant::now();
-        let start = Instant::now();
-
-        loop {
-            if last_printed.elapsed().as_millis() >= 5000 {
-                last_printed = Instant::now();
-                self.print_update_with_target(&start, p_value);
-
-                if let Some(results) = self.luckiest_stream() {
-                    if results.luck(&self.barter_drop_list, &self.blaze_drop_list) <= p_value {
-                        *self.completed.write().unwrap() = true;
-                        break;
-                    }
-                }
-            }
-
-            thread::yield_now();
-        }
-
-        self.luckiest_stream().unwrap()
-    }
-
-    /// The goals of the simulation.
-    pub fn goals(&self) -> &SimulationGoals {
-        &self.goals
-    }
-
-    /// Prints a message updating the user on the status of the simulation.
-    fn print_update_with_progress(&self, start: &Instant, target_num_streams: u64) {
-        let luckiest_stream = self.luckiest_stream();
-        let streams = self.simulations() * self.goals.streams.len() as u64;
-        let streams_per_second = streams / start.elapsed().as_secs();
-        let completed = streams as f32 / target_num_streams as f32;
-
-        let time_remaining: humantime::Duration = std::time::Duration::from_secs(
-            (target_num_streams - std::cmp::min(streams, target_num_streams)) as u64
-                / std::cmp::max(1, streams_per_second),
-        )
-        .into();
-
-        let total_time_estimate: humantime::Duration = std::time::Duration::from_secs(
-            target_num_streams / std::cmp::max(1, streams_per_second),
-        )
-        .into();
-
-        if let Some(luckiest_stream) = luckiest_stream {
-            println!(
-                "luckiest stream: {} ({} barters, {} fights), streams simulated: {}/{}, streams per second: {}, complete: {}%, est: {}/{}",
-                luckiest_stream.luck(&self.barter_drop_list, &self.blaze_drop_list),
-                luckiest_stream.total_barters,
-                luckiest_stream.total_fights,
-                streams,
-                target_num_streams,
-                streams_per_second,
-                completed * 100.0,
-                time_remaining,
-                total_time_estimate,
-            );
-        } else {
-            println!(
-                "streams simulated: {}/{}, streams per second: {}, complete: {}%, est: {}/{}",
-                streams,
-                target_num_streams,
-                streams_per_second,
-                completed * 100.0,
-                time_remaining,
-                total_time_estimate,
-            );
-        }
-    }
-
-    /// Prints a message updating the user on the status of the simulation.
-    fn print_update_with_target(&self, start: &Instant, target_p_value: f64) {
-        let luckiest_stream = self.luckiest_stream();
-        let streams = self.simulations() * self.goals.streams.len() as u64;
-        let streams_per_second = streams / start.elapsed().as_secs();
-        let time_elapsed: humantime::Duration = start.elapsed().into();
-
-        if let Some(luckiest_stream) = luckiest_stream {
-            println!(
-                "luckiest stream: {} ({} barters, {} fights), target luck: {}, streams simulated: {}, streams per second: {}, elapsed: {}",
-                luckiest_stream.luck(&self.barter_drop_list, &self.blaze_drop_list),
-                luckiest_stream.total_barters,
-                luckiest_stream.total_fights,
-                target_p_value,
-                streams,
-                streams_per_second,
-                time_elapsed,
-            );
-        } else {
-            println!(
-                "target luck: {}, streams simulated: {}, streams per second: {}, elapsed: {}",
-                target_p_value,
-                streams,
-                streams_per_second,
-                time_elapsed,
-            );
-        }
-    }
-
-    /// Get the number of simulations that have been run in total from all worker threads (approximated while they are running).
-    fn simulations(&self) -> u64 {
-        self.workers.iter().map(|worker| worker.simulations()).sum()
-    }
-
-    /// Get the luckiest stream that has been simulated from across all worker threads (approximated while they are running).
-    fn luckiest_stream(&self) -> Option<StreamResults> {
-        self.workers
-            .iter()
-            .map(|worker| {
-                worker
-                    .luckiest_stream()
-                    .as_ref()
-                    .map(|stream| stream.results())
-            })
-            .filter(|results| results.is_some())
-            .map(|results| results.unwrap())
-            .min_by(|lhs, rhs| {
-                lhs.luck(&self.barter_drop_list, &self.blaze_drop_list)
-                    .partial_cmp(&rhs.luck(&self.barter_drop_list, &self.blaze_drop_list))
-                    .unwrap()
-            })
-    }
-
-    /// Consumes the simulation and produces stream results.
-    fn into_results(self) -> Vec<StreamResults> {
-        self.workers
-            .into_iter()
-            .flat_map(|worker| worker.into_thread().join().unwrap())
-            .collect()
-    }
-
-    fn drop_lists(
-        goals: &SimulationGoals,
-    ) -> (
-        DropList<EnderPearlDistribution>,
-        DropList<BlazeRodDistribution>,
-    ) {
-        let ender_pearl_target_total = goals
-            .streams
-            .iter()
-            .map(|s| s.iter().map(|r| r.target_pearls).sum::<u32>())
-            .sum();
-
-        let ender_pearl_target_per_run =
-            ender_pearl_target_total / goals.streams.iter().map(|s| s.len() as u32).sum::<u32>();
-
-        let barter_drop_list =
-            drop_list::barter_drop_list(ender_pearl_target_total, ender_pearl_target_per_run);
-
+    pub fn new(goals: &SimulationGoals) -> Self {
         let blaze_rod_target = goals
             .streams
             .iter()
             .map(|s| s.iter().map(|r| r.target_rods).sum::<u32>())
             .sum();
 
-        let blaze_drop_list = drop_list::blaze_drop_list(blaze_rod_target);
-
-        (barter_drop_list, blaze_drop_list)
+        Self {
+            barter: drop_list::barter_drop_list_per_stream(&goals.streams),
+            blaze: drop_list::blaze_drop_list(blaze_rod_target),
+        }
     }
 }
+
+/// A metric that ranks stream results, where a lower value is luckier.
+pub type LuckMetric = Arc<dyn Fn(&StreamResults) -> f64 + Send + Sync>;
+
+/// The number of streams simulated per second, given how long it took to simulate them.
+/// Durations under a millisecond are treated as a millisecond, so this never divides by zero.
+/// ```
+/// # use mc_sim::sim::*;
+/// # use std::time::Duration;
+/// assert_eq!(streams_per_second(1000, Duration::from_secs(2)), 500);
+/// assert_eq!(streams_per_second(1000, Duration::from_nanos(1)), 1000000);
+/// assert_eq!(streams_per_second(0, Duration::from_secs(0)), 0);
+/// ```
+pub fn streams_per_second(streams: u64, elapsed: Duration) -> u64 {
+    (streams as f64 / elapsed.as_secs_f64().max(0.001)) as u64
+}
@@ -0,0 +1,1243 @@
+use super::{streams_per_second, LuckMetric, SimulationDropLists, SimulationGoals};
+use crate::drop::RareDrop;
+use crate::error::McSimError;
+use crate::stats::{self, LuckEstimate, SimulationHistogram};
+use crate::stream::{Stream, StreamResults};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+impl SimulationGoals {
+    /// A sampler over the weighted streams, or `None` if every stream has the same weight.
+    fn stream_sampler(&self) -> Result<Option<WeightedIndex<f64>>, McSimError> {
+        if self.weights.is_empty() {
+            return Ok(None);
+        }
+
+        if self.weights.len() != self.streams.len() {
+            return Err(McSimError::MismatchedStreamWeights(
+                self.weights.len(),
+                self.streams.len(),
+            ));
+        }
+
+        let weights: Vec<f64> = (0..self.streams.len()).map(|s| self.weight(s)).collect();
+        let sampler = WeightedIndex::new(&weights).map_err(McSimError::InvalidStreamWeights)?;
+
+        if weights.iter().all(|w| *w == weights[0]) {
+            Ok(None)
+        } else {
+            Ok(Some(sampler))
+        }
+    }
+}
+
+/// Which of the streams simulated by each worker thread are kept in the results.
+/// Streams before `warmup` are counted but not kept, and at most `limit` streams are kept.
+#[derive(Debug, Clone, Copy, Default)]
+struct SampleWindow {
+    warmup: u64,
+    limit: Option<u64>,
+}
+
+impl SampleWindow {
+    /// Whether the stream at an index in a worker thread's sequence should be kept,
+    /// given the number of streams that have been kept so far.
+    fn keeps(&self, index: u64, kept: usize) -> bool {
+        let under_limit = match self.limit {
+            Some(limit) => (kept as u64) < limit,
+            None => true,
+        };
+
+        index >= self.warmup && under_limit
+    }
+
+    /// Removes any kept streams that fall outside of the window.
+    /// The kept streams must be consecutive in the sequence, starting from the index `kept_from`.
+    fn trim(&self, data: &mut Vec<StreamResults>, kept_from: &mut u64) {
+        let warmup = std::cmp::min(self.warmup.saturating_sub(*kept_from), data.len() as u64);
+        data.drain(..warmup as usize);
+        *kept_from += warmup;
+
+        if let Some(limit) = self.limit {
+            data.truncate(limit as usize);
+        }
+    }
+}
+
+/// The status of a worker thread as it simulates streams, which is read while the simulation is running.
+#[derive(Debug, Default)]
+struct WorkerStatus {
+    /// The number of streams simulated.
+    simulations: AtomicU64,
+    /// The luck that streams are tallied against. Like the sample window, this is only checked every now and then.
+    lucky_threshold: RwLock<Option<f64>>,
+    /// The number of streams tallied since the threshold was seen.
+    tallied: AtomicU64,
+    /// The number of tallied streams that were at least as lucky as the threshold.
+    lucky: AtomicU64,
+    /// The rarest individual drop seen, which is only updated every now and then.
+    rarest_drop: RwLock<Option<RareDrop>>,
+    /// How often the thread checks in, which is fixed when the thread is created.
+    update_interval: Duration,
+    /// Whether the thread tracks the rarest drop, which is also fixed when the thread is created.
+    track_rarest_drop: bool,
+}
+
+/// A single thread used in simulating minecraft runs.
+/// All the actual work is done on worker threads, not on the main thread.
+struct SimulationThread {
+    luckiest_stream: Arc<RwLock<Option<Stream>>>,
+    status: Arc<WorkerStatus>,
+    thread: JoinHandle<Vec<StreamResults>>,
+}
+
+impl SimulationThread {
+    /// Create a simulation thread, or an error if the thread couldn't be spawned.
+    /// The `completed` locked-bool is used to stop the thread.
+    /// The seeds of its streams are drawn from `seed` if there is one, or else from entropy.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        completed: Arc<RwLock<bool>>,
+        sample_window: Arc<RwLock<SampleWindow>>,
+        metric: Arc<RwLock<Option<LuckMetric>>>,
+        goals: SimulationGoals,
+        drop_lists: Arc<SimulationDropLists>,
+        options: &SimulationOptions,
+        seed: Option<u64>,
+    ) -> Result<Self, McSimError> {
+        let luckiest_stream = Arc::new(RwLock::new(None));
+        let status = Arc::new(WorkerStatus {
+            update_interval: options.worker_update_interval,
+            track_rarest_drop: options.track_rarest_drop,
+            ..WorkerStatus::default()
+        });
+
+        Ok(Self {
+            luckiest_stream: Arc::clone(&luckiest_stream),
+            status: Arc::clone(&status),
+            thread: thread::Builder::new()
+                .name(name)
+                .spawn(move || {
+                    SimulationThread::run(
+                        goals,
+                        completed,
+                        sample_window,
+                        metric,
+                        luckiest_stream,
+                        status,
+                        drop_lists,
+                        seed,
+                    )
+                })
+                .map_err(McSimError::ThreadSpawnFailed)?,
+        })
+    }
+
+    /// The number of simulations that have been completed.
+    /// This is approximate while the thread is running, since it is read without synchronisation.
+    pub fn simulations(&self) -> u64 {
+        self.status.simulations.load(Ordering::Relaxed)
+    }
+
+    /// The number of streams tallied against the lucky threshold, and how many of those were at least as lucky.
+    /// This is approximate while the thread is running, like [simulations](SimulationThread::simulations).
+    pub fn lucky_tally(&self) -> (u64, u64) {
+        (
+            self.status.lucky.load(Ordering::Relaxed),
+            self.status.tallied.load(Ordering::Relaxed),
+        )
+    }
+
+    /// The rarest individual drop seen so far by this worker thread.
+    pub fn rarest_drop(&self) -> Option<RareDrop> {
+        self.status.rarest_drop.read().unwrap().clone()
+    }
+
+    /// Sets the luck that streams are tallied against, which the thread sees the next time it checks in.
+    pub fn set_lucky_threshold(&self, threshold: f64) {
+        *self.status.lucky_threshold.write().unwrap() = Some(threshold);
+    }
+
+    /// The luckiest stream seen so far by this worker thread.
+    pub fn luckiest_stream(&self) -> RwLockReadGuard<'_, Option<Stream>> {
+        self.luckiest_stream.read().unwrap()
+    }
+
+    /// Consumes the simulation thread into a join handle, which provides the stream results.
+    pub fn into_thread(self) -> JoinHandle<Vec<StreamResults>> {
+        self.thread
+    }
+
+    /// Runs the simulation.
+    #[allow(clippy::too_many_arguments)]
+    fn run(
+        goals: SimulationGoals,
+        completed: Arc<RwLock<bool>>,
+        sample_window: Arc<RwLock<SampleWindow>>,
+        metric_lock: Arc<RwLock<Option<LuckMetric>>>,
+        luckiest_stream: Arc<RwLock<Option<Stream>>>,
+        status: Arc<WorkerStatus>,
+        drop_lists: Arc<SimulationDropLists>,
+        seed: Option<u64>,
+    ) -> Vec<StreamResults> {
+        // Each stream is simulated from its own seed, so that the luckiest stream can be replayed.
+        // The seeds are drawn from an RNG that belongs to this thread.
+        let mut seeds = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        // The drop simulators are reseeded for each stream, rather than being created again.
+        let (mut barter_drop_sim, mut blaze_drop_sim) =
+            Stream::seeded_drop_sims(drop_lists.barter.list(), drop_lists.blaze.list(), 0);
+
+        // The worker shares the rarest drop it has seen, which the drop simulators only track when asked to.
+        if status.track_rarest_drop {
+            barter_drop_sim.track_rarest_drop();
+            blaze_drop_sim.track_rarest_drop();
+        }
+
+        // The results of running a simulation are just simple StreamResults.
+        // The entire streams could be stored and returned, but that would eat memory fast.
+        let mut data = Vec::<StreamResults>::new();
+        let mut tries = 0;
+        let mut last_update = Instant::now();
+
+        // The window of streams to keep is only checked every now and then, along with the completed flag.
+        // Streams kept before the window was seen are trimmed once it is.
+        let mut window = *sample_window.read().unwrap();
+        let mut kept_from = 0;
+
+        // The metric that ranks streams, or the combined luck if there is none.
+        // Like the window, this is only checked every now and then.
+        let mut metric = metric_lock.read().unwrap().clone();
+
+        // Tracks the best stream so far. Starts as unreasonably bad luck, so that we immediately replace this.
+        let mut personal_best_luck = f64::INFINITY;
+
+        // Streams are only tallied against the lucky threshold once it has been seen.
+        let mut lucky_threshold = None;
+        let (mut tallied, mut lucky) = (0, 0);
+
+        // Weighted streams are sampled, rather than each being simulated once per cycle.
+        let stream_sampler = goals
+            .stream_sampler()
+            .expect("the stream weights are checked when the simulation is created");
+
+        loop {
+            for stream_goals in goals.streams.iter() {
+                let run_goals = match &stream_sampler {
+                    Some(sampler) => &goals.streams[sampler.sample(&mut seeds)],
+                    None => stream_goals,
+                };
+
+                // Only the results of each stream are simulated, which avoids allocating for every drop.
+                // The full stream is replayed from its seed if it turns out to be the luckiest.
+                let seed = seeds.gen();
+                Stream::reseed_drop_sims(&mut barter_drop_sim, &mut blaze_drop_sim, seed);
+                #[cfg(not(feature = "item_totals"))]
+                let results = Stream::simulate_results_only(
+                    &mut barter_drop_sim,
+                    &mut blaze_drop_sim,
+                    run_goals,
+                );
+                // The item totals need every drop, so the whole stream is simulated to count them.
+                #[cfg(feature = "item_totals")]
+                let results =
+                    Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, run_goals.clone())
+                        .results();
+                tries += 1;
+                status.simulations.store(tries, Ordering::Relaxed);
+
+                // Luck is computed for every stream, since a stream with more barters (or fights) than our PB
+                // can still be luckier overall when it has far fewer of the other.
+                let luck = match &metric {
+                    Some(metric) => metric(&results),
+                    None => results.luck(&drop_lists.barter, &drop_lists.blaze),
+                };
+
+                // Only actually grab the luckiest stream rwlock when we know we've beaten our PB.
+                if personal_best_luck > luck {
+                    personal_best_luck = luck;
+
+                    let stream = Stream {
+                        sequence_index: Some(tries - 1),
+                        ..Stream::simulate_seeded(
+                            drop_lists.barter.list(),
+                            drop_lists.blaze.list(),
+                            run_goals.clone(),
+                            seed,
+                        )
+                    };
+                    *luckiest_stream.write().unwrap() = Some(stream);
+                }
+
+                if let Some(threshold) = lucky_threshold {
+                    tallied += 1;
+                    status.tallied.store(tallied, Ordering::Relaxed);
+
+                    if luck <= threshold {
+                        lucky += 1;
+                        status.lucky.store(lucky, Ordering::Relaxed);
+                    }
+                }
+
+                if window.keeps(tries - 1, data.len()) {
+                    data.push(results);
+                }
+            }
+
+            // Every now and then, check if we should stop because the completed flag is set.
+            // This is done to avoid hogging the rwlocks.
+            if last_update.elapsed() >= status.update_interval {
+                last_update = Instant::now();
+
+                window = *sample_window.read().unwrap();
+                window.trim(&mut data, &mut kept_from);
+
+                // The personal best is meaningless under a different metric, so start over.
+                let latest_metric = metric_lock.read().unwrap().clone();
+                let metric_changed = match (&metric, &latest_metric) {
+                    (Some(metric), Some(latest_metric)) => !Arc::ptr_eq(metric, latest_metric),
+                    (None, None) => false,
+                    _ => true,
+                };
+
+                lucky_threshold = *status.lucky_threshold.read().unwrap();
+
+                // Each drop simulator keeps its own rarest drop, so only the rarer of the two is shared.
+                let rarest_drop = barter_drop_sim
+                    .rarest_drop()
+                    .into_iter()
+                    .chain(blaze_drop_sim.rarest_drop())
+                    .min_by(|lhs, rhs| lhs.probability.total_cmp(&rhs.probability));
+                *status.rarest_drop.write().unwrap() = rarest_drop.cloned();
+
+                if metric_changed {
+                    metric = latest_metric;
+                    personal_best_luck = f64::INFINITY;
+                }
+
+                if *completed.read().unwrap() {
+                    break;
+                }
+            }
+        }
+
+        data
+    }
+}
+
+/// A projection of how long a simulation will take, and how much memory its results will use.
+/// See: [Simulation::estimate]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimulationEstimate {
+    /// The streams simulated per second, measured while calibrating.
+    pub streams_per_second: f64,
+    /// The projected wall-clock time to simulate all of the cycles.
+    pub duration: Duration,
+    /// The projected size of the stream results for all of the cycles, in bytes.
+    pub memory_bytes: u64,
+}
+
+/// The progress of a running simulation, as given to [SimulationOptions::on_progress].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimulationProgress {
+    /// The streams simulated so far, in total from all worker threads.
+    pub streams: u64,
+    /// The time since progress started being reported.
+    pub elapsed: Duration,
+    /// The streams simulated per second since progress started being reported.
+    pub streams_per_second: u64,
+}
+
+/// A callback that is given the progress of a simulation every time it is reported.
+pub type ProgressCallback = Arc<dyn Fn(&SimulationProgress) + Send + Sync>;
+
+/// Options for how a simulation runs and reports its progress. See: [Simulation::new_with_options]
+/// The default options check in with the worker threads every 2 seconds, and print progress every 5 seconds.
+#[derive(Clone)]
+pub struct SimulationOptions {
+    /// How often each worker thread shares its status and sees changes to its settings, such as being stopped.
+    pub worker_update_interval: Duration,
+    /// How often progress is reported, which is also how often most ways of running the simulation check if
+    /// they are done.
+    pub report_interval: Duration,
+    /// Whether progress is printed to stdout when it is reported.
+    pub print_progress: bool,
+    /// A callback that is given the progress every time it is reported.
+    pub on_progress: Option<ProgressCallback>,
+    /// Whether the worker threads track the rarest drop, for [Simulation::rarest_drop].
+    /// This costs a little for every drop, so it's off by default.
+    pub track_rarest_drop: bool,
+}
+
+impl Default for SimulationOptions {
+    fn default() -> Self {
+        Self {
+            worker_update_interval: Duration::from_secs(2),
+            report_interval: Duration::from_secs(5),
+            print_progress: true,
+            on_progress: None,
+            track_rarest_drop: false,
+        }
+    }
+}
+
+/// The results of a simulation, along with how long it took. See: [Simulation::simulate_n_times_reported]
+#[derive(Debug, Clone)]
+pub struct SimulationReport {
+    pub results: Vec<StreamResults>,
+    pub elapsed: Duration,
+    pub streams_per_second: u64,
+}
+
+/// A simulation of a series of streams of speed runs, distributed over worker threads.
+pub struct Simulation {
+    goals: SimulationGoals,
+    completed: Arc<RwLock<bool>>,
+    sample_window: Arc<RwLock<SampleWindow>>,
+    metric: Arc<RwLock<Option<LuckMetric>>>,
+    workers: Vec<SimulationThread>,
+    drop_lists: Arc<SimulationDropLists>,
+    options: SimulationOptions,
+    started: Instant,
+}
+
+impl Simulation {
+    /// Create a simulation.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new(goals, 4);
+    /// let results = simulation.simulate_n_times(100);
+    /// # assert!(results.len() >= 100);
+    /// ```
+    pub fn new(goals: SimulationGoals, thread_count: u32) -> Self {
+        let drop_lists = Arc::new(SimulationDropLists::new(&goals));
+        Simulation::new_with_drop_lists(goals, thread_count, drop_lists)
+    }
+
+    /// Create a simulation, like [new](Simulation::new), but with an error rather than a panic if no worker threads
+    /// could be spawned. If only some of the worker threads could be spawned, the simulation runs on fewer threads.
+    /// See: [worker_count](Simulation::worker_count)
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::try_new(goals, 4).unwrap();
+    /// assert!(simulation.worker_count() >= 1 && simulation.worker_count() <= 4);
+    /// # simulation.into_results_iter().count();
+    /// ```
+    pub fn try_new(goals: SimulationGoals, thread_count: u32) -> Result<Self, McSimError> {
+        let drop_lists = Arc::new(SimulationDropLists::new(&goals));
+        Simulation::try_new_with_drop_lists(goals, thread_count, drop_lists)
+    }
+
+    /// Create a simulation that will be run for a known number of cycles, with no more worker threads than cycles.
+    /// This avoids wasted work when only a few cycles are needed.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new_for_cycles(goals, 32, 2);
+    /// assert!(simulation.worker_count() <= 2);
+    /// let results = simulation.simulate_n_times(2);
+    /// # assert!(results.len() >= 2);
+    /// ```
+    pub fn new_for_cycles(goals: SimulationGoals, thread_count: u32, cycles: u64) -> Self {
+        let thread_count = thread_count.min(cycles.clamp(1, u32::MAX as u64) as u32);
+        Simulation::new(goals, thread_count)
+    }
+
+    /// Create a simulation with a worker thread for each unit of available parallelism,
+    /// or a single worker thread if that can't be determined.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new_auto(goals);
+    /// assert!(simulation.worker_count() >= 1);
+    /// # simulation.into_results_iter().count();
+    /// ```
+    pub fn new_auto(goals: SimulationGoals) -> Self {
+        let thread_count = thread::available_parallelism()
+            .map(|n| n.get() as u32)
+            .unwrap_or(1);
+
+        Simulation::new(goals, thread_count)
+    }
+
+    /// Create a simulation that uses drop lists which have already been created.
+    /// The drop lists (and their distributions) are shared between all of the worker threads,
+    /// rather than being created again for each of them.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// # use std::sync::Arc;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let drop_lists = Arc::new(SimulationDropLists::new(&goals));
+    /// let simulation = Simulation::new_with_drop_lists(goals, 4, Arc::clone(&drop_lists));
+    ///
+    /// // Ours, the simulation's, and one for each of the worker threads.
+    /// assert_eq!(Arc::strong_count(&drop_lists), 6);
+    /// assert!(Arc::ptr_eq(simulation.drop_lists(), &drop_lists));
+    /// # simulation.into_results_iter().count();
+    /// ```
+    pub fn new_with_drop_lists(
+        goals: SimulationGoals,
+        thread_count: u32,
+        drop_lists: Arc<SimulationDropLists>,
+    ) -> Self {
+        Simulation::try_new_with_drop_lists(goals, thread_count, drop_lists)
+            .expect("no simulation worker threads could be spawned")
+    }
+
+    /// Create a simulation that uses drop lists which have already been created, with an error rather than a panic
+    /// if no worker threads could be spawned. See: [try_new](Simulation::try_new)
+    pub fn try_new_with_drop_lists(
+        goals: SimulationGoals,
+        thread_count: u32,
+        drop_lists: Arc<SimulationDropLists>,
+    ) -> Result<Self, McSimError> {
+        Simulation::try_new_with_all(
+            goals,
+            thread_count,
+            drop_lists,
+            SimulationOptions::default(),
+            None,
+        )
+    }
+
+    /// Create a simulation with options for how often it checks in with the worker threads and reports progress.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// # use std::sync::{atomic::{AtomicU64, Ordering}, Arc};
+    /// # use std::time::Duration;
+    /// let reports = Arc::new(AtomicU64::new(0));
+    /// let counter = Arc::clone(&reports);
+    /// let options = SimulationOptions {
+    ///     worker_update_interval: Duration::from_millis(10),
+    ///     report_interval: Duration::from_millis(10),
+    ///     print_progress: false,
+    ///     on_progress: Some(Arc::new(move |_| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     })),
+    ///     track_rarest_drop: false,
+    /// };
+    ///
+    /// // The default options wouldn't report any progress, or see that the simulation is done, for 5 seconds.
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let start = std::time::Instant::now();
+    /// let simulation = Simulation::new_with_options(goals, 4, options);
+    /// simulation.simulate_n_times(100);
+    /// assert!(reports.load(Ordering::Relaxed) >= 1);
+    /// assert!(start.elapsed() < Duration::from_secs(5));
+    /// ```
+    pub fn new_with_options(
+        goals: SimulationGoals,
+        thread_count: u32,
+        options: SimulationOptions,
+    ) -> Self {
+        let drop_lists = Arc::new(SimulationDropLists::new(&goals));
+        Simulation::try_new_with_all(goals, thread_count, drop_lists, options, None)
+            .expect("no simulation worker threads could be spawned")
+    }
+
+    /// Create a simulation whose worker threads draw the seeds of their streams from a seed, rather than from entropy.
+    /// Each worker thread is seeded differently. With a single worker thread, the streams it simulates are the same
+    /// every time, so the results of [simulate_collecting](Simulation::simulate_collecting) can be reproduced.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let lhs = Simulation::new_seeded(goals.clone(), 1, 42).simulate_collecting(0, 100);
+    /// let rhs = Simulation::new_seeded(goals, 1, 42).simulate_collecting(0, 100);
+    /// assert_eq!(lhs, rhs);
+    /// ```
+    pub fn new_seeded(goals: SimulationGoals, thread_count: u32, seed: u64) -> Self {
+        let drop_lists = Arc::new(SimulationDropLists::new(&goals));
+        Simulation::try_new_with_all(
+            goals,
+            thread_count,
+            drop_lists,
+            SimulationOptions::default(),
+            Some(seed),
+        )
+        .expect("no simulation worker threads could be spawned")
+    }
+
+    /// Create a simulation from everything that can be given to it.
+    fn try_new_with_all(
+        goals: SimulationGoals,
+        thread_count: u32,
+        drop_lists: Arc<SimulationDropLists>,
+        options: SimulationOptions,
+        seed: Option<u64>,
+    ) -> Result<Self, McSimError> {
+        goals.stream_sampler()?;
+
+        let completed = Arc::new(RwLock::new(false));
+        let sample_window = Arc::new(RwLock::new(SampleWindow::default()));
+        let metric = Arc::new(RwLock::new(None));
+
+        let mut workers = Vec::new();
+        let mut spawn_error = None;
+
+        for id in 0..thread_count {
+            match SimulationThread::new(
+                format!("Simulation Worker Thread #{}", id),
+                Arc::clone(&completed),
+                Arc::clone(&sample_window),
+                Arc::clone(&metric),
+                goals.clone(),
+                Arc::clone(&drop_lists),
+                &options,
+                seed.map(|seed| seed.wrapping_add(id as u64)),
+            ) {
+                Ok(worker) => workers.push(worker),
+                Err(err) => spawn_error = Some(err),
+            }
+        }
+
+        // Carry on with fewer workers, unless none of them could be spawned.
+        if let (true, Some(err)) = (workers.is_empty(), spawn_error) {
+            return Err(err);
+        }
+
+        Ok(Self {
+            goals,
+            completed,
+            sample_window,
+            metric,
+            workers,
+            drop_lists,
+            options,
+            started: Instant::now(),
+        })
+    }
+
+    /// Run the simulation for a given number of cycles and get the results.
+    /// This will consume the simulator.
+    pub fn simulate_n_times(self, cycles: u64) -> Vec<StreamResults> {
+        self.wait_for_cycles(cycles);
+        self.into_results()
+    }
+
+    /// Run the simulation for a given number of cycles, and get the results along with the time it took,
+    /// measured from when the simulation was created. This will consume the simulator.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new(goals, 4);
+    /// let report = simulation.simulate_n_times_reported(100);
+    /// assert!(report.results.len() >= 100);
+    /// assert!(report.elapsed.as_secs_f64() > 0.0);
+    /// assert!(report.streams_per_second > 0);
+    /// ```
+    pub fn simulate_n_times_reported(self, cycles: u64) -> SimulationReport {
+        let started = self.started;
+        let results = self.simulate_n_times(cycles);
+        let elapsed = started.elapsed();
+
+        SimulationReport {
+            streams_per_second: streams_per_second(results.len() as u64, elapsed),
+            results,
+            elapsed,
+        }
+    }
+
+    /// Run the simulation, discarding the first `warmup` streams simulated by each worker thread,
+    /// and then collect exactly `collect` stream results.
+    /// Each worker thread keeps at most `collect` results, which bounds the memory used.
+    /// This will consume the simulator.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new(goals, 4);
+    /// let results = simulation.simulate_collecting(10, 50);
+    /// assert_eq!(results.len(), 50);
+    /// ```
+    pub fn simulate_collecting(self, warmup: u64, collect: u64) -> Vec<StreamResults> {
+        *self.sample_window.write().unwrap() = SampleWindow {
+            warmup,
+            limit: Some(collect),
+        };
+
+        // Every worker thread has kept all it can once this many streams have been simulated in total,
+        // or else there are enough kept between them.
+        self.wait_for_cycles(self.workers.len() as u64 * warmup + collect);
+        self.into_results_iter().take(collect as usize).collect()
+    }
+
+    /// Run the simulation for a given number of cycles, recording exactly that many stream results in a histogram
+    /// on top of the counts of a checkpoint, if there is one. This will consume the simulator.
+    /// Saving the returned histogram allows a long simulation to be stopped and resumed later on.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let checkpoint = Simulation::new(goals.clone(), 4).simulate_n_times_resumable(100, None);
+    /// assert_eq!(checkpoint.total(), 100);
+    ///
+    /// let json = serde_json::to_string(&checkpoint).unwrap();
+    /// let checkpoint = serde_json::from_str(&json).unwrap();
+    /// let histogram = Simulation::new(goals, 4).simulate_n_times_resumable(50, Some(checkpoint));
+    /// assert_eq!(histogram.total(), 150);
+    /// ```
+    pub fn simulate_n_times_resumable(
+        self,
+        cycles: u64,
+        checkpoint: Option<SimulationHistogram>,
+    ) -> SimulationHistogram {
+        let mut histogram = checkpoint.unwrap_or_default();
+
+        for results in self.simulate_n_times_iter(cycles).take(cycles as usize) {
+            histogram.record(&results);
+        }
+
+        histogram
+    }
+
+    /// Run the simulation for a given number of cycles and get an iterator over the results.
+    /// This will consume the simulator. See: [into_results_iter](Simulation::into_results_iter)
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new(goals, 4);
+    /// let streams = simulation.simulate_n_times_iter(100).count();
+    /// # assert!(streams >= 100);
+    /// ```
+    pub fn simulate_n_times_iter(self, cycles: u64) -> impl Iterator<Item = StreamResults> {
+        self.wait_for_cycles(cycles);
+        self.into_results_iter()
+    }
+
+    /// Stops the simulation and consumes it into an iterator over the stream results.
+    /// Worker threads are joined lazily as the iterator reaches them, so the results from all workers
+    /// are never collected together unless the caller does so.
+    pub fn into_results_iter(self) -> impl Iterator<Item = StreamResults> {
+        *self.completed.write().unwrap() = true;
+
+        self.workers
+            .into_iter()
+            .flat_map(|worker| worker.into_thread().join().unwrap())
+    }
+
+    /// Waits for the worker threads to complete a given number of cycles, printing updates along the way.
+    fn wait_for_cycles(&self, cycles: u64) {
+        let mut last_printed = Instant::now();
+        let start = Instant::now();
+
+        loop {
+            if last_printed.elapsed() >= self.options.report_interval {
+                last_printed = Instant::now();
+                self.report_progress(&start);
+
+                if self.options.print_progress {
+                    self.print_update_with_progress(
+                        &start,
+                        cycles * self.goals.streams.len() as u64,
+                    );
+                }
+
+                if self.simulations() >= cycles {
+                    *self.completed.write().unwrap() = true;
+                    break;
+                }
+            }
+
+            thread::yield_now();
+        }
+    }
+
+    /// Estimates how long it will take to simulate a number of cycles, and how much memory the results will use.
+    /// This measures the running simulation for a short calibration period, without stopping it.
+    /// Fails if the simulation has no worker threads, as it would never make progress.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new(goals, 4);
+    /// let estimate = simulation.estimate(1_000_000_000).unwrap();
+    /// assert!(estimate.streams_per_second > 0.0);
+    /// assert!(estimate.duration.as_secs_f64() > 0.0);
+    /// assert!(estimate.memory_bytes > 0);
+    ///
+    /// // Estimates too large to represent saturate.
+    /// let estimate = simulation.estimate(u64::MAX).unwrap();
+    /// assert_eq!(estimate.memory_bytes, u64::MAX);
+    /// # simulation.into_results_iter().count();
+    ///
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// assert!(Simulation::new(goals, 0).estimate(100).is_err());
+    /// ```
+    pub fn estimate(&self, cycles: u64) -> Result<SimulationEstimate, McSimError> {
+        if self.workers.is_empty() {
+            return Err(McSimError::NoWorkers);
+        }
+
+        let streams_per_cycle = self.goals.streams.len() as u64;
+        let start = Instant::now();
+        let start_simulations = self.simulations();
+
+        // Calibrate for at least 100ms, and until at least one simulation has finished.
+        while start.elapsed() < Duration::from_millis(100)
+            || self.simulations() == start_simulations
+        {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let streams = self.simulations() - start_simulations;
+        let streams_per_second = streams as f64 / start.elapsed().as_secs_f64();
+
+        let total_streams = cycles.saturating_mul(streams_per_cycle);
+
+        Ok(SimulationEstimate {
+            streams_per_second,
+            duration: Duration::try_from_secs_f64(total_streams as f64 / streams_per_second)
+                .unwrap_or(Duration::MAX),
+            memory_bytes: (std::mem::size_of::<StreamResults>() as u64)
+                .saturating_mul(total_streams),
+        })
+    }
+
+    /// Run the simulation until a desired p-value is reached.
+    /// I.E. The luckiest run seen, is as lucky, or luckier than the given p-value.
+    pub fn run_to_p_value(self, p_value: f64) -> StreamResults {
+        let drop_lists = Arc::clone(&self.drop_lists);
+        self.run_to_target(p_value, move |results| {
+            results.luck(&drop_lists.barter, &drop_lists.blaze)
+        })
+    }
+
+    /// Run the simulation until the luckiest stream seen, as ranked by a metric, is at or below a target value.
+    /// The metric maps stream results to the value being minimized, in place of the combined luck.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// // Rank by the fewest total barters, regardless of rods.
+    /// let goals = SimulationGoalsBuilder::new().add_run(10, 7).goals();
+    /// let simulation = Simulation::new(goals, 4);
+    /// let results = simulation.run_to_p_value_with(30.0, |results| results.total_barters as f64);
+    /// assert!(results.total_barters <= 30);
+    /// ```
+    pub fn run_to_p_value_with<F>(self, p_value: f64, metric: F) -> StreamResults
+    where
+        F: Fn(&StreamResults) -> f64 + Send + Sync + 'static,
+    {
+        let metric: LuckMetric = Arc::new(metric);
+        *self.metric.write().unwrap() = Some(Arc::clone(&metric));
+        self.run_to_target(p_value, move |results| metric(results))
+    }
+
+    /// Run the simulation until the luckiest stream is as lucky as each of a set of p-values, recording when each was
+    /// first reached. Each entry has the threshold, the time since the simulation was created, and the luckiest stream
+    /// at that point. The simulation stops once the lowest threshold is reached, or after `max_cycles` cycles,
+    /// so thresholds that weren't reached are left out. The entries are in the order the thresholds were reached.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// let goals = SimulationGoalsBuilder::new().add_runs(2, 10, 7).goals();
+    /// let simulation = Simulation::new(goals, 4);
+    /// let drop_lists = std::sync::Arc::clone(simulation.drop_lists());
+    /// let reached = simulation.run_to_p_values(&[0.01, 0.1], 1_000_000_000);
+    ///
+    /// assert_eq!(reached.len(), 2);
+    /// assert_eq!(reached[0].0, 0.1);
+    /// assert_eq!(reached[1].0, 0.01);
+    /// assert!(reached[0].1 <= reached[1].1);
+    /// for (threshold, _, results) in reached {
+    ///     assert!(results.luck(&drop_lists.barter, &drop_lists.blaze) <= threshold);
+    /// }
+    /// ```
+    pub fn run_to_p_values(
+        self,
+        thresholds: &[f64],
+        max_cycles: u64,
+    ) -> Vec<(f64, Duration, StreamResults)> {
+        let metric =
+            |results: &StreamResults| results.luck(&self.drop_lists.barter, &self.drop_lists.blaze);
+
+        // The highest thresholds are the easiest, so they're reached first.
+        let mut remaining = thresholds.to_vec();
+        remaining.sort_by(|lhs, rhs| rhs.total_cmp(lhs));
+
+        let mut reached = Vec::new();
+        let mut last_printed = Instant::now();
+        let start = Instant::now();
+
+        while !remaining.is_empty() && self.simulations() < max_cycles {
+            thread::sleep(Duration::from_millis(100));
+
+            if let Some(results) = self.luckiest_stream_by(&metric) {
+                let luck = metric(&results);
+                let elapsed = self.started.elapsed();
+
+                while let Some(&threshold) = remaining.first() {
+                    if luck > threshold {
+                        break;
+                    }
+
+                    reached.push((threshold, elapsed, results.clone()));
+                    remaining.remove(0);
+                }
+            }
+
+            if let (Some(&threshold), true) = (
+                remaining.first(),
+                last_printed.elapsed() >= self.options.report_interval,
+            ) {
+                last_printed = Instant::now();
+                self.report_progress(&start);
+
+                if self.options.print_progress {
+                    self.print_update_with_target(&start, threshold, &metric);
+                }
+            }
+        }
+
+        self.into_results_iter().for_each(drop);
+        reached
+    }
+
+    /// Run the simulation until the probability of a stream being at least as lucky as `observed_luck` is known
+    /// to within a relative error, at a confidence level. Luck is ranked by the metric given to
+    /// [run_to_p_value_with](Simulation::run_to_p_value_with) if there is one, or else the combined luck.
+    /// The probability is the proportion of simulated streams that were at least as lucky, and the simulation stops
+    /// once the half-width of its Wilson score interval is at most `relative_error` times the estimate,
+    /// or after `max_cycles` cycles, with the best estimate so far. Only streams simulated after this is called are
+    /// counted.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// // Being at least as lucky as 0.5 is common, so this converges quickly.
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new(goals.clone(), 4);
+    /// let estimate = simulation.run_to_confidence(0.5, 0.1, 0.95, 1_000_000_000).unwrap();
+    /// assert!(estimate.lower <= estimate.probability && estimate.probability <= estimate.upper);
+    /// assert!(estimate.relative_error() <= 0.1);
+    /// assert!(estimate.probability > 0.0);
+    ///
+    /// // Nothing is ever this lucky, so this stops at the cycle limit without reaching the relative error.
+    /// let simulation = Simulation::new(goals, 4);
+    /// let estimate = simulation.run_to_confidence(1e-300, 0.1, 0.95, 1000).unwrap();
+    /// assert_eq!(estimate.probability, 0.0);
+    /// assert!(estimate.relative_error() > 0.1);
+    /// ```
+    pub fn run_to_confidence(
+        self,
+        observed_luck: f64,
+        relative_error: f64,
+        confidence: f64,
+        max_cycles: u64,
+    ) -> Result<LuckEstimate, McSimError> {
+        // Check the confidence before spending any time simulating.
+        LuckEstimate::wilson(0, 0, confidence)?;
+
+        for worker in self.workers.iter() {
+            worker.set_lucky_threshold(observed_luck);
+        }
+
+        let mut last_printed = Instant::now();
+        let start = Instant::now();
+
+        let estimate = loop {
+            thread::sleep(Duration::from_millis(100));
+
+            let (lucky_streams, streams) = self.lucky_tally();
+            let estimate = LuckEstimate::wilson(lucky_streams, streams, confidence)?;
+
+            if estimate.relative_error() <= relative_error || self.simulations() >= max_cycles {
+                break estimate;
+            }
+
+            if last_printed.elapsed() >= self.options.report_interval {
+                last_printed = Instant::now();
+                self.report_progress(&start);
+
+                if self.options.print_progress {
+                    println!(
+                        "probability of luck {}: {} ({} to {}), streams tallied: {}, elapsed: {}",
+                        observed_luck,
+                        estimate.probability,
+                        estimate.lower,
+                        estimate.upper,
+                        estimate.streams,
+                        humantime::Duration::from(start.elapsed()),
+                    );
+                }
+            }
+        };
+
+        self.into_results_iter().for_each(drop);
+        Ok(estimate)
+    }
+
+    /// Runs the simulation until the luckiest stream by a metric is at or below a target value.
+    fn run_to_target(self, target: f64, metric: impl Fn(&StreamResults) -> f64) -> StreamResults {
+        let mut last_printed = Instant::now();
+        let start = Instant::now();
+
+        loop {
+            if last_printed.elapsed() >= self.options.report_interval {
+                last_printed = Instant::now();
+                self.report_progress(&start);
+
+                if self.options.print_progress {
+                    self.print_update_with_target(&start, target, &metric);
+                }
+
+                if let Some(results) = self.luckiest_stream_by(&metric) {
+                    if metric(&results) <= target {
+                        *self.completed.write().unwrap() = true;
+                        break;
+                    }
+                }
+            }
+
+            thread::yield_now();
+        }
+
+        self.luckiest_stream_by(&metric).unwrap()
+    }
+
+    /// The number of worker threads running the simulation.
+    pub fn worker_count(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// The goals of the simulation.
+    pub fn goals(&self) -> &SimulationGoals {
+        &self.goals
+    }
+
+    /// The drop lists used by the simulation.
+    pub fn drop_lists(&self) -> &Arc<SimulationDropLists> {
+        &self.drop_lists
+    }
+
+    /// Gives the progress of the simulation to the progress callback, if there is one.
+    fn report_progress(&self, start: &Instant) {
+        if let Some(on_progress) = &self.options.on_progress {
+            on_progress(&SimulationProgress {
+                streams: self.simulations(),
+                elapsed: start.elapsed(),
+                streams_per_second: self.streams_per_second(*start),
+            });
+        }
+    }
+
+    /// Prints a message updating the user on the status of the simulation.
+    fn print_update_with_progress(&self, start: &Instant, target_num_streams: u64) {
+        let luckiest_stream = self.luckiest_stream();
+        let streams = self.simulations() * self.goals.streams.len() as u64;
+        let streams_per_second = streams_per_second(streams, start.elapsed());
+        let completed = streams as f32 / target_num_streams as f32;
+
+        let time_remaining: humantime::Duration = std::time::Duration::from_secs(
+            (target_num_streams - std::cmp::min(streams, target_num_streams)) as u64
+                / std::cmp::max(1, streams_per_second),
+        )
+        .into();
+
+        let total_time_estimate: humantime::Duration = std::time::Duration::from_secs(
+            target_num_streams / std::cmp::max(1, streams_per_second),
+        )
+        .into();
+
+        if let Some(luckiest_stream) = luckiest_stream {
+            println!(
+                "luckiest stream: {} ({} barters, {} fights), streams simulated: {}/{}, streams per second: {}, complete: {}%, est: {}/{}",
+                luckiest_stream.luck(&self.drop_lists.barter, &self.drop_lists.blaze),
+                luckiest_stream.total_barters,
+                luckiest_stream.total_fights,
+                streams,
+                target_num_streams,
+                streams_per_second,
+                completed * 100.0,
+                time_remaining,
+                total_time_estimate,
+            );
+        } else {
+            println!(
+                "streams simulated: {}/{}, streams per second: {}, complete: {}%, est: {}/{}",
+                streams,
+                target_num_streams,
+                streams_per_second,
+                completed * 100.0,
+                time_remaining,
+                total_time_estimate,
+            );
+        }
+    }
+
+    /// Prints a message updating the user on the status of the simulation.
+    fn print_update_with_target(
+        &self,
+        start: &Instant,
+        target_p_value: f64,
+        metric: &impl Fn(&StreamResults) -> f64,
+    ) {
+        let luckiest_stream = self.luckiest_stream_by(metric);
+        let streams = self.simulations() * self.goals.streams.len() as u64;
+        let streams_per_second = streams_per_second(streams, start.elapsed());
+        let time_elapsed: humantime::Duration = start.elapsed().into();
+
+        if let Some(luckiest_stream) = luckiest_stream {
+            println!(
+                "luckiest stream: {} ({} barters, {} fights), target luck: {}, streams simulated: {}, streams per second: {}, elapsed: {}",
+                metric(&luckiest_stream),
+                luckiest_stream.total_barters,
+                luckiest_stream.total_fights,
+                target_p_value,
+                streams,
+                streams_per_second,
+                time_elapsed,
+            );
+        } else {
+            println!(
+                "target luck: {}, streams simulated: {}, streams per second: {}, elapsed: {}",
+                target_p_value, streams, streams_per_second, time_elapsed,
+            );
+        }
+    }
+
+    /// Get the number of simulations that have been run in total from all worker threads (approximated while they are running).
+    fn simulations(&self) -> u64 {
+        self.workers.iter().map(|worker| worker.simulations()).sum()
+    }
+
+    /// The streams simulated per second since `start` across all worker threads, for monitoring a running simulation.
+    /// Like the progress reports, this only counts what the worker threads have checked in with so far.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// # use std::{thread, time::{Duration, Instant}};
+    /// let options = SimulationOptions {
+    ///     worker_update_interval: Duration::from_millis(10),
+    ///     print_progress: false,
+    ///     ..SimulationOptions::default()
+    /// };
+    ///
+    /// let start = Instant::now();
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new_with_options(goals, 4, options);
+    /// while simulation.streams_per_second(start) == 0 && start.elapsed() < Duration::from_secs(10) {
+    ///     thread::sleep(Duration::from_millis(10));
+    /// }
+    ///
+    /// assert!(simulation.streams_per_second(start) > 0);
+    /// # simulation.into_results_iter().count();
+    /// ```
+    pub fn streams_per_second(&self, start: Instant) -> u64 {
+        streams_per_second(self.simulations(), start.elapsed())
+    }
+
+    /// Get the rarest individual drop that has been simulated from across all worker threads.
+    /// Each worker thread only shares its rarest drop every couple of seconds, so this lags behind the simulation.
+    /// The rarest drop is only tracked when [SimulationOptions::track_rarest_drop] is set, and is `None` otherwise.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// # use std::{thread, time::Duration};
+    /// let options = SimulationOptions {
+    ///     track_rarest_drop: true,
+    ///     print_progress: false,
+    ///     ..SimulationOptions::default()
+    /// };
+    /// let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    /// let simulation = Simulation::new_with_options(goals.clone(), 4, options);
+    /// thread::sleep(Duration::from_millis(2500));
+    ///
+    /// let rarest = simulation.rarest_drop().unwrap();
+    /// assert!(rarest.probability > 0.0 && rarest.probability < 0.01);
+    /// # simulation.into_results_iter().count();
+    ///
+    /// let simulation = Simulation::new(goals, 4);
+    /// thread::sleep(Duration::from_millis(2500));
+    /// assert_eq!(simulation.rarest_drop(), None);
+    /// # simulation.into_results_iter().count();
+    /// ```
+    pub fn rarest_drop(&self) -> Option<RareDrop> {
+        self.workers
+            .iter()
+            .filter_map(|worker| worker.rarest_drop())
+            .min_by(|lhs, rhs| lhs.probability.total_cmp(&rhs.probability))
+    }
+
+    /// Get the luckiest stream that has been simulated from across all worker threads, with all of its drops,
+    /// such as to save it with [save_json](Stream::save_json). Streams are ranked by the metric being simulated to,
+    /// or by their combined luck if there isn't one. Like [rarest_drop](Simulation::rarest_drop), this is
+    /// approximate while the worker threads are running.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// # use std::{thread, time::Duration};
+    /// let simulation = Simulation::new(SimulationGoals::dream_scenario(), 2);
+    /// thread::sleep(Duration::from_millis(500));
+    ///
+    /// let stream = simulation.luckiest_full_stream().unwrap();
+    /// let drop_lists = std::sync::Arc::clone(simulation.drop_lists());
+    /// let luck = stream.results().luck(&drop_lists.barter, &drop_lists.blaze);
+    /// let results: Vec<_> = simulation.into_results_iter().collect();
+    ///
+    /// // The full stream is one of the simulated streams, with the same luck as its results.
+    /// let simulated = results
+    ///     .iter()
+    ///     .find(|r| (r.total_barters, r.total_fights) == (stream.total_barters(), stream.total_fights()))
+    ///     .unwrap();
+    /// assert_eq!(simulated.luck(&drop_lists.barter, &drop_lists.blaze), luck);
+    /// ```
+    pub fn luckiest_full_stream(&self) -> Option<Stream> {
+        let streams: Vec<Stream> = self
+            .workers
+            .iter()
+            .filter_map(|worker| worker.luckiest_stream().clone())
+            .collect();
+        let results: Vec<StreamResults> = streams.iter().map(Stream::results).collect();
+
+        let luckiest = match self.metric.read().unwrap().clone() {
+            Some(metric) => stats::luckiest_by(&results, |results| metric(results)),
+            None => stats::luckiest_by(&results, |results| {
+                results.luck(&self.drop_lists.barter, &self.drop_lists.blaze)
+            }),
+        }?;
+
+        let index = results
+            .iter()
+            .position(|results| std::ptr::eq(results, luckiest))?;
+        streams.into_iter().nth(index)
+    }
+
+    /// Get the number of streams at least as lucky as the lucky threshold, and the number of streams tallied against it,
+    /// in total from all worker threads (approximated while they are running).
+    fn lucky_tally(&self) -> (u64, u64) {
+        self.workers.iter().map(|worker| worker.lucky_tally()).fold(
+            (0, 0),
+            |(lucky, tallied), (worker_lucky, worker_tallied)| {
+                (lucky + worker_lucky, tallied + worker_tallied)
+            },
+        )
+    }
+
+    /// Get the luckiest stream that has been simulated from across all worker threads (approximated while they are running).
+    fn luckiest_stream(&self) -> Option<StreamResults> {
+        self.luckiest_stream_by(&|results: &StreamResults| {
+            results.luck(&self.drop_lists.barter, &self.drop_lists.blaze)
+        })
+    }
+
+    /// Get the luckiest stream from across all worker threads, as ranked by a metric (approximated while they are running).
+    fn luckiest_stream_by(&self, metric: &impl Fn(&StreamResults) -> f64) -> Option<StreamResults> {
+        let streams: Vec<StreamResults> = self
+            .workers
+            .iter()
+            .filter_map(|worker| {
+                worker
+                    .luckiest_stream()
+                    .as_ref()
+                    .map(|stream| stream.results())
+            })
+            .collect();
+
+        stats::luckiest_by(&streams, metric).cloned()
+    }
+
+    /// Consumes the simulation and produces stream results.
+    fn into_results(self) -> Vec<StreamResults> {
+        self.into_results_iter().collect()
+    }
+}
@@ -1,15 +1,27 @@
 use crate::drop::{DropConfig, Item};
+use crate::drop_list::{self, DropList};
 use crate::error::McSimError;
+use crate::run::RunGoals;
+use crate::stream::StreamResults;
 use cached::proc_macro::cached;
 use fraction::BigUint;
 use fraction::Zero;
-use statrs::distribution::{Discrete, NegativeBinomial, Univariate};
+#[cfg(feature = "threaded")]
+use rayon::prelude::*;
+use statrs::distribution::{Discrete, InverseCDF, NegativeBinomial, Normal, StudentsT, Univariate};
+use statrs::statistics::{Mean, Variance};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+use std::convert::TryFrom;
+use std::io::{Read, Write};
+use std::sync::Mutex;
 type F = fraction::GenericFraction<BigUint>;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct EnderPearlDistribution {
     ender_pearl_target_total: u32,
     ender_pearl_target_per_run: u32,
+    mean_drops_to_reach_target: f64,
     distribution: NegativeBinomial,
 }
 
@@ -20,7 +32,7 @@ impl EnderPearlDistribution {
     ///
     /// This distribution is most accurate when all runs within the stream have the same target pearl drops.
     /// Deviating from that by much creates an offset in the data, that provides an unrealistic favourability towards
-    /// good luck typically.
+    /// good luck typically. [new_exact](EnderPearlDistribution::new_exact) avoids this, given the target of each run.
     ///
     /// When constrained in this way, this distribution is fairly accurate.
     /// For this reason, I suggest that you feed in modified data rather than the exact run data from dream's streams.
@@ -64,6 +76,160 @@ impl EnderPearlDistribution {
         .map(|distribution| Self {
             ender_pearl_target_total,
             ender_pearl_target_per_run,
+            mean_drops_to_reach_target: EnderPearlDistribution::successes_to_reach_target(
+                ender_pearl_target_per_run,
+                ender_pearl_target_per_run,
+                drop_list,
+            ),
+            distribution,
+        })
+    }
+
+    /// Creates a distribution like [new](EnderPearlDistribution::new), but models each stream with its own
+    /// target pearls per run and then composes them into one distribution.
+    ///
+    /// This reduces the offset that [new](EnderPearlDistribution::new) has when runs have different targets.
+    /// Taken to the extreme, each run can be given its own stream, which removes the offset in the mean entirely.
+    /// ```
+    /// # use mc_sim::drop::DropSim;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stats::EnderPearlDistribution;
+    /// # use mc_sim::stream::Stream;
+    /// let barter_drop_list = drop_list::barter_drop_list(20, 10);
+    /// let blaze_drop_list = drop_list::blaze_drop_list(0);
+    /// let mut barter_drop_sim = DropSim::new(barter_drop_list.list_clone());
+    /// let mut blaze_drop_sim = DropSim::new(blaze_drop_list.list_clone());
+    ///
+    /// // A stream that has one run targeting 1 pearl, and another targeting 19.
+    /// let goals = vec![RunGoals::pearls_only(1), RunGoals::pearls_only(19)];
+    /// let samples = 5000;
+    /// let mean_failures = (0..samples)
+    ///     .map(|_| Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, goals.clone()).results())
+    ///     .map(|results| (results.total_barters - results.successful_barters) as f64)
+    ///     .sum::<f64>() / samples as f64;
+    ///
+    /// let averaged = barter_drop_list.distribution().unwrap();
+    /// let per_stream = EnderPearlDistribution::new_per_stream(
+    ///     &[vec![goals[0]], vec![goals[1]]],
+    ///     barter_drop_list.list(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(per_stream.ender_pearl_target_total(), 20);
+    /// assert!(
+    ///     (mean_failures - per_stream.expected_failures()).abs()
+    ///         < (mean_failures - averaged.expected_failures()).abs()
+    /// );
+    /// ```
+    pub fn new_per_stream(
+        streams: &[Vec<RunGoals>],
+        drop_list: &[DropConfig],
+    ) -> Result<Self, McSimError> {
+        let stream_totals = streams
+            .iter()
+            .map(|s| checked_total(s.iter().map(|r| r.target_pearls), "target pearls"))
+            .collect::<Result<Vec<u32>, McSimError>>()?;
+        let ender_pearl_target_total =
+            checked_total(stream_totals.iter().copied(), "target pearls")?;
+        let runs = checked_total(streams.iter().map(|s| s.len() as u32), "runs")?;
+        let successes = streams
+            .iter()
+            .zip(&stream_totals)
+            .map(|(s, &total)| {
+                let per_run = total.checked_div(s.len() as u32).unwrap_or(0);
+                EnderPearlDistribution::successes_to_reach_target(total, per_run, drop_list)
+            })
+            .sum();
+
+        negative_binomial(
+            successes,
+            item_drop_probability(drop_list, Item::EnderPearl),
+        )
+        .map(|distribution| Self {
+            ender_pearl_target_total,
+            ender_pearl_target_per_run: ender_pearl_target_total.checked_div(runs).unwrap_or(0),
+            mean_drops_to_reach_target: if runs == 0 {
+                0.0
+            } else {
+                successes / runs as f64
+            },
+            distribution,
+        })
+    }
+
+    /// Creates a distribution like [new](EnderPearlDistribution::new), but from the exact pearl target of each run,
+    /// rather than from the total and the average per run. This is [new_per_stream](EnderPearlDistribution::new_per_stream)
+    /// with each run in a stream of its own.
+    ///
+    /// The successful barters that each run needs to reach its own target are modelled separately, and summed.
+    /// Since the sum of independent negative binomials with the same probability is a negative binomial with their
+    /// successes summed, this is the distribution of the stream's failed barters, without the offset that
+    /// [new](EnderPearlDistribution::new) has when runs have different targets.
+    /// ```
+    /// # use mc_sim::drop::DropSim;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stats::EnderPearlDistribution;
+    /// # use mc_sim::stream::Stream;
+    /// # use statrs::distribution::Univariate;
+    /// let targets = [2, 10, 18, 2, 18];
+    /// let total = targets.iter().sum::<u32>();
+    /// let barter_drop_list = drop_list::barter_drop_list(total, total / targets.len() as u32);
+    /// let blaze_drop_list = drop_list::blaze_drop_list(0);
+    /// let mut barter_drop_sim = DropSim::from_seed(barter_drop_list.list_clone(), 3);
+    /// let mut blaze_drop_sim = DropSim::from_seed(blaze_drop_list.list_clone(), 4);
+    ///
+    /// let goals: Vec<RunGoals> = targets.iter().map(|&target| RunGoals::pearls_only(target)).collect();
+    /// let mut failures: Vec<u32> = (0..10000)
+    ///     .map(|_| Stream::simulate_results_only(&mut barter_drop_sim, &mut blaze_drop_sim, &goals))
+    ///     .map(|results| results.total_barters - results.successful_barters)
+    ///     .collect();
+    /// failures.sort_unstable();
+    ///
+    /// // The largest difference between the empirical cdf of the failures and the distribution's cdf.
+    /// let distance = |distribution: &EnderPearlDistribution| {
+    ///     (0..failures.len())
+    ///         .map(|i| {
+    ///             let empirical = (i + 1) as f64 / failures.len() as f64;
+    ///             (empirical - distribution.distribution().cdf(failures[i] as f64)).abs()
+    ///         })
+    ///         .fold(0.0, f64::max)
+    /// };
+    ///
+    /// let exact = EnderPearlDistribution::new_exact(&targets, barter_drop_list.list()).unwrap();
+    /// let averaged = barter_drop_list.distribution().unwrap();
+    /// assert_eq!(exact.ender_pearl_target_total(), total);
+    /// assert!(distance(&exact) < distance(&averaged));
+    /// ```
+    pub fn new_exact(
+        per_run_targets: &[u32],
+        drop_list: &[DropConfig],
+    ) -> Result<Self, McSimError> {
+        let streams: Vec<Vec<RunGoals>> = per_run_targets
+            .iter()
+            .map(|&target| vec![RunGoals::pearls_only(target)])
+            .collect();
+
+        EnderPearlDistribution::new_per_stream(&streams, drop_list)
+    }
+
+    /// Creates a distribution from the successful barters it needs across the stream and for each run,
+    /// which is how distributions that weren't made with [new](EnderPearlDistribution::new) are rebuilt.
+    pub(crate) fn from_successes(
+        ender_pearl_target_total: u32,
+        ender_pearl_target_per_run: u32,
+        successes: f64,
+        mean_drops_to_reach_target: f64,
+        drop_list: &[DropConfig],
+    ) -> Result<Self, McSimError> {
+        negative_binomial(
+            successes,
+            item_drop_probability(drop_list, Item::EnderPearl),
+        )
+        .map(|distribution| Self {
+            ender_pearl_target_total,
+            ender_pearl_target_per_run,
+            mean_drops_to_reach_target,
             distribution,
         })
     }
@@ -73,9 +239,121 @@ impl EnderPearlDistribution {
         &self.distribution
     }
 
+    /// The total number of ender pearls targeted across the stream.
+    pub fn ender_pearl_target_total(&self) -> u32 {
+        self.ender_pearl_target_total
+    }
+
+    /// The number of ender pearls targeted per run in the stream.
+    pub fn ender_pearl_target_per_run(&self) -> u32 {
+        self.ender_pearl_target_per_run
+    }
+
+    /// The probability of a single barter dropping ender pearls.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(10, 10);
+    /// let distribution = drop_list.distribution().unwrap();
+    /// assert_eq!(distribution.drop_probability(), 20.0 / 423.0);
+    /// assert!((distribution.mean_drops_to_reach_target() - 2.12).abs() < 1e-9);
+    /// ```
+    pub fn drop_probability(&self) -> f64 {
+        self.distribution.p()
+    }
+
+    /// The mean number of barters that drop ender pearls needed to reach the target pearls for a run.
+    pub fn mean_drops_to_reach_target(&self) -> f64 {
+        self.mean_drops_to_reach_target
+    }
+
+    /// The expected number of failed barters (barters that didn't drop pearls) to reach the target.
+    pub fn expected_failures(&self) -> f64 {
+        self.distribution.mean()
+    }
+
+    /// The variance of the number of failed barters to reach the target.
+    pub fn variance(&self) -> f64 {
+        self.distribution.variance()
+    }
+
+    /// The expected total number of barters to reach the target, including the successful barters.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(10, 10);
+    /// let distribution = drop_list.distribution().unwrap();
+    /// assert!((distribution.expected_total_barters() - 2.12 * 423.0 / 20.0).abs() < 1e-9);
+    /// ```
+    pub fn expected_total_barters(&self) -> f64 {
+        self.expected_failures() + self.distribution.r()
+    }
+
+    /// The most likely total number of barters to reach the target, including the successful barters.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(220, 10);
+    /// let distribution = drop_list.distribution().unwrap();
+    /// let mode = distribution.mode() as f64;
+    /// assert!(mode < distribution.expected_total_barters());
+    /// assert!(mode > distribution.expected_total_barters() * 0.9);
+    /// ```
+    pub fn mode(&self) -> u32 {
+        if self.targets_no_pearls() {
+            return 0;
+        }
+
+        (negative_binomial_mode(&self.distribution) as f64 + self.distribution.r().round()) as u32
+    }
+
+    /// The smallest total number of barters (including the successful barters) where the luck is at least `p`.
+    /// This is the inverse of [luck](EnderPearlDistribution::luck), taking the successful barters to be the mean
+    /// number needed to reach the target.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::barter_drop_list(220, 10);
+    /// let distribution = drop_list.distribution().unwrap();
+    /// let successful_barters = distribution.distribution().r().round() as u32;
+    ///
+    /// let median = distribution.quantile(0.5).unwrap();
+    /// assert!((median as f64 - distribution.expected_total_barters()).abs() < 10.0);
+    ///
+    /// let lucky = distribution.quantile(0.01).unwrap();
+    /// assert!(distribution.luck(lucky, successful_barters) >= 0.01);
+    /// assert!(distribution.luck(lucky - 1, successful_barters) < 0.01);
+    ///
+    /// assert!(distribution.quantile(1.5).is_err());
+    /// assert!(distribution.quantile(f64::NAN).is_err());
+    /// ```
+    pub fn quantile(&self, p: f64) -> Result<u32, McSimError> {
+        check_quantile(p)?;
+
+        if self.targets_no_pearls() {
+            return Ok(0);
+        }
+
+        Ok(negative_binomial_quantile(&self.distribution, p)
+            .saturating_add(self.distribution.r().round() as u64)
+            .min(u32::MAX as u64) as u32)
+    }
+
     /// An estimate of the luck of the total number of barters and number of successful barters resulting
     /// in the target number of ender pearls, based on this distribution.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// // Targeting no pearls needs no barters, so it is certain.
+    /// let drop_list = drop_list::barter_drop_list(0, 0);
+    /// let distribution = drop_list.distribution().unwrap();
+    /// assert_eq!(distribution.luck(0, 0), 1.0);
+    /// assert_eq!(distribution.probability(0, 0), 1.0);
+    /// assert_eq!(distribution.probability(3, 0), 0.0);
+    /// assert_eq!(distribution.mode(), 0);
+    /// assert_eq!(distribution.quantile(0.5).unwrap(), 0);
+    /// ```
     pub fn luck(&self, total_barters_made: u32, successful_barters: u32) -> f64 {
+        // The negative binomial can't compute its cdf with no successes, but the answer is certain.
+        if self.targets_no_pearls() {
+            return 1.0;
+        }
+
         self.distribution
             .cdf(total_barters_made as f64 - successful_barters as f64)
     }
@@ -83,10 +361,41 @@ impl EnderPearlDistribution {
     /// An estimate of the probability of the specific total number of barters and number of successful barters resulting
     /// in the target number of ender pearls, based on this distribution.
     pub fn probability(&self, total_barters_made: u32, successful_barters: u32) -> f64 {
+        if self.targets_no_pearls() {
+            return if total_barters_made <= successful_barters {
+                1.0
+            } else {
+                0.0
+            };
+        }
+
         self.distribution
             .pmf((total_barters_made as i32 - successful_barters as i32) as u64)
     }
 
+    /// An estimate of the unluck of the total number of barters and number of successful barters resulting
+    /// in the target number of ender pearls, based on this distribution.
+    /// This is the upper tail: the probability of needing at least this many barters.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use statrs::distribution::Discrete;
+    /// let drop_list = drop_list::barter_drop_list(170, 10);
+    /// let distribution = drop_list.distribution().unwrap();
+    ///
+    /// // 800 barters with 36 successful is 764 failed barters, so the tail is everything from 764 failures up.
+    /// let expected = 1.0 - (0..764).map(|f| distribution.distribution().pmf(f)).sum::<f64>();
+    /// assert!((distribution.unlucky(800, 36) - expected).abs() < 1e-9);
+    /// ```
+    pub fn unlucky(&self, total_barters_made: u32, successful_barters: u32) -> f64 {
+        1.0 - self.luck(total_barters_made, successful_barters)
+            + self.probability(total_barters_made, successful_barters)
+    }
+
+    /// Whether no successful barters are needed, which the negative binomial can't compute anything for.
+    fn targets_no_pearls(&self) -> bool {
+        self.distribution.r() == 0.0
+    }
+
     /// Creates the actual distribution.
     /// Described in the documentation for [new](EnderPearlDistribution::new).
     fn create_distribution(
@@ -95,6 +404,28 @@ impl EnderPearlDistribution {
         drop_list: &[DropConfig],
     ) -> Result<NegativeBinomial, McSimError> {
         let drop_probability = item_drop_probability(drop_list, Item::EnderPearl);
+
+        negative_binomial(
+            EnderPearlDistribution::successes_to_reach_target(
+                ender_pearl_target_total,
+                ender_pearl_target_per_run,
+                drop_list,
+            ),
+            drop_probability,
+        )
+    }
+
+    /// The mean number of successful barters needed to reach the target pearls for a stream.
+    /// Streams that target no pearls need no successful barters.
+    fn successes_to_reach_target(
+        ender_pearl_target_total: u32,
+        ender_pearl_target_per_run: u32,
+        drop_list: &[DropConfig],
+    ) -> f64 {
+        if ender_pearl_target_total == 0 {
+            return 0.0;
+        }
+
         let drop_range = item_drop_range(drop_list, Item::EnderPearl);
 
         let mean_drops_to_reach_target = attempts_to_reach_target(
@@ -103,16 +434,12 @@ impl EnderPearlDistribution {
             ender_pearl_target_per_run as i32,
         );
 
-        NegativeBinomial::new(
-            ender_pearl_target_total as f64 / ender_pearl_target_per_run as f64
-                * mean_drops_to_reach_target,
-            drop_probability,
-        )
-        .map_err(|_| McSimError::InvalidDistribution)
+        ender_pearl_target_total as f64 / ender_pearl_target_per_run as f64
+            * mean_drops_to_reach_target
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BlazeRodDistribution {
     blaze_rod_target: u32,
     distribution: NegativeBinomial,
@@ -139,6 +466,21 @@ impl BlazeRodDistribution {
     /// // This produces the same number calculated in the mods paper.
     /// assert_eq!(probability_of_dream_luck, 0.000000000008791412042796765);
     /// ```
+    ///
+    /// A target of zero blaze rods is reached without any fights, so the distribution is certain to see no failures.
+    /// Any number of blazes killed is then as lucky as can be, and only killing none at all has any probability.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::blaze_drop_list(0);
+    /// let distribution = drop_list.distribution().unwrap();
+    ///
+    /// assert_eq!(distribution.expected_total_blazes(), 0.0);
+    /// assert_eq!(distribution.luck(0), 1.0);
+    /// assert_eq!(distribution.luck(10), 1.0);
+    /// assert_eq!(distribution.probability(0), 1.0);
+    /// assert_eq!(distribution.probability(10), 0.0);
+    /// assert_eq!(distribution.unlucky(10), 0.0);
+    /// ```
     pub fn new(blaze_rod_target: u32, drop_list: &[DropConfig]) -> Result<Self, McSimError> {
         BlazeRodDistribution::create_distribution(blaze_rod_target, drop_list).map(|distribution| {
             Self {
@@ -153,9 +495,86 @@ impl BlazeRodDistribution {
         &self.distribution
     }
 
+    /// The total number of blaze rods targeted across the stream.
+    pub fn blaze_rod_target(&self) -> u32 {
+        self.blaze_rod_target
+    }
+
+    /// The expected number of failed blaze fights (fights that didn't drop a rod) to reach the target.
+    pub fn expected_failures(&self) -> f64 {
+        self.distribution.mean()
+    }
+
+    /// The variance of the number of failed blaze fights to reach the target.
+    pub fn variance(&self) -> f64 {
+        self.distribution.variance()
+    }
+
+    /// The expected total number of blazes killed to reach the target, including the successful fights.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::blaze_drop_list(7);
+    /// let distribution = drop_list.distribution().unwrap();
+    /// assert_eq!(distribution.expected_failures(), 7.0);
+    /// assert_eq!(distribution.variance(), 14.0);
+    /// assert_eq!(distribution.expected_total_blazes(), 14.0);
+    /// ```
+    pub fn expected_total_blazes(&self) -> f64 {
+        self.expected_failures() + self.blaze_rod_target as f64
+    }
+
+    /// The most likely number of blazes killed to reach the target, including the successful fights.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::blaze_drop_list(7);
+    /// let distribution = drop_list.distribution().unwrap();
+    /// // The mode is just below the expected 14 kills, since the distribution is skewed towards more kills.
+    /// assert!((12..=13).contains(&distribution.mode()));
+    /// assert_eq!(drop_list::blaze_drop_list(0).distribution().unwrap().mode(), 0);
+    /// ```
+    pub fn mode(&self) -> u32 {
+        if self.blaze_rod_target == 0 {
+            return 0;
+        }
+
+        negative_binomial_mode(&self.distribution) as u32 + self.blaze_rod_target
+    }
+
+    /// The smallest number of blazes killed (including the successful fights) where the luck is at least `p`.
+    /// This is the inverse of [luck](BlazeRodDistribution::luck).
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// let drop_list = drop_list::blaze_drop_list(154);
+    /// let distribution = drop_list.distribution().unwrap();
+    ///
+    /// assert!((distribution.quantile(0.5).unwrap() as f64 - 308.0).abs() <= 2.0);
+    ///
+    /// let lucky = distribution.quantile(0.01).unwrap();
+    /// assert!((distribution.luck(lucky) - 0.01).abs() < 0.005);
+    /// assert!(distribution.luck(lucky) >= 0.01 && distribution.luck(lucky - 1) < 0.01);
+    ///
+    /// assert!(distribution.quantile(-0.1).is_err());
+    /// ```
+    pub fn quantile(&self, p: f64) -> Result<u32, McSimError> {
+        check_quantile(p)?;
+
+        if self.blaze_rod_target == 0 {
+            return Ok(0);
+        }
+
+        Ok(negative_binomial_quantile(&self.distribution, p)
+            .saturating_add(self.blaze_rod_target as u64)
+            .min(u32::MAX as u64) as u32)
+    }
+
     /// An estimate of the luck of the number of blazes killed to obtain the target number of blaze rods,
     /// based on this distribution.
     pub fn luck(&self, total_blazes_killed: u32) -> f64 {
+        // The negative binomial can't compute its cdf with no successes, but the answer is certain.
+        if self.blaze_rod_target == 0 {
+            return 1.0;
+        }
+
         self.distribution
             .cdf(total_blazes_killed as f64 - self.blaze_rod_target as f64)
     }
@@ -163,21 +582,201 @@ impl BlazeRodDistribution {
     /// An estimate of the probability of the specific number of blazes killed to obtain the target number of blaze rods,
     /// based on this distribution.
     pub fn probability(&self, total_blazes_killed: u32) -> f64 {
+        if self.blaze_rod_target == 0 {
+            return if total_blazes_killed == 0 { 1.0 } else { 0.0 };
+        }
+
         self.distribution
             .pmf((total_blazes_killed as i32 - self.blaze_rod_target as i32) as u64)
     }
 
+    /// An estimate of the unluck of the number of blazes killed to obtain the target number of blaze rods,
+    /// based on this distribution.
+    /// This is the upper tail: the probability of needing to kill at least this many blazes.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use statrs::distribution::Discrete;
+    /// let drop_list = drop_list::blaze_drop_list(211);
+    /// let distribution = drop_list.distribution().unwrap();
+    ///
+    /// // 430 blazes for 211 rods is 219 failed fights, so the tail is everything from 219 failures up.
+    /// let expected = 1.0 - (0..219).map(|f| distribution.distribution().pmf(f)).sum::<f64>();
+    /// assert!((distribution.unlucky(430) - expected).abs() < 1e-9);
+    /// assert!(distribution.unlucky(305) > 0.999);
+    /// ```
+    pub fn unlucky(&self, total_blazes_killed: u32) -> f64 {
+        1.0 - self.luck(total_blazes_killed) + self.probability(total_blazes_killed)
+    }
+
     /// Creates the actual distribution.
     /// Described in the documentation for [new](BlazeRodDistribution::new).
     fn create_distribution(
         blaze_rod_target: u32,
         drop_list: &[DropConfig],
     ) -> Result<NegativeBinomial, McSimError> {
-        NegativeBinomial::new(
+        // A fight only drops rods when the rod is picked, which is every fight for the vanilla drop list.
+        negative_binomial(
             blaze_rod_target as f64,
-            item_drop_average(drop_list, Item::BlazeRod),
+            item_drop_probability(drop_list, Item::BlazeRod)
+                * item_drop_average(drop_list, Item::BlazeRod),
+        )
+    }
+}
+
+/// Sums values without wrapping, giving an error naming the total if it doesn't fit in a `u32`.
+/// ```
+/// # use mc_sim::error::McSimError;
+/// # use mc_sim::stats;
+/// assert_eq!(stats::checked_total(vec![1, 2, 3], "pearls").unwrap(), 6);
+///
+/// let error = stats::checked_total(vec![u32::MAX, 1], "pearls").unwrap_err();
+/// assert!(matches!(error, McSimError::TotalOverflow("pearls")));
+/// ```
+pub fn checked_total(
+    values: impl IntoIterator<Item = u32>,
+    total: &'static str,
+) -> Result<u32, McSimError> {
+    let sum = values.into_iter().map(u64::from).sum::<u64>();
+    u32::try_from(sum).map_err(|_| McSimError::TotalOverflow(total))
+}
+
+/// The most likely number of failures of a negative binomial distribution.
+/// The pmf is scanned around the analytical mode, `floor((r - 1)(1 - p) / p)`, since that can land on either side
+/// of a tie, or be slightly off when `r` isn't a whole number.
+fn negative_binomial_mode(distribution: &NegativeBinomial) -> u64 {
+    let (r, p) = (distribution.r(), distribution.p());
+    let analytical = ((r - 1.0) * (1.0 - p) / p).floor().max(0.0);
+
+    if !analytical.is_finite() || analytical >= u32::MAX as f64 {
+        return u32::MAX as u64;
+    }
+
+    let analytical = analytical as u64;
+    (analytical.saturating_sub(1)..=analytical + 1)
+        .map(|failures| (failures, distribution.pmf(failures)))
+        .filter(|(_, pmf)| !pmf.is_nan())
+        .max_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+        .map(|(failures, _)| failures)
+        .unwrap_or(analytical)
+}
+
+/// Checks that the probability a quantile is asked for is within 0 to 1.
+fn check_quantile(p: f64) -> Result<(), McSimError> {
+    if (0.0..=1.0).contains(&p) {
+        Ok(())
+    } else {
+        Err(McSimError::InvalidPercentile(p))
+    }
+}
+
+/// The smallest number of failures of a negative binomial distribution where the cdf is at least `p`.
+/// There's no inverse cdf for the negative binomial, so this searches over the cdf, which only ever increases.
+fn negative_binomial_quantile(distribution: &NegativeBinomial, p: f64) -> u64 {
+    let max = u32::MAX as u64;
+
+    // Find an upper bound by doubling, and then binary search below it.
+    let mut upper = 1;
+    while upper < max && distribution.cdf(upper as f64) < p {
+        upper = std::cmp::min(upper * 2, max);
+    }
+
+    let mut lower = 0;
+    while lower < upper {
+        let middle = lower + (upper - lower) / 2;
+
+        if distribution.cdf(middle as f64) < p {
+            lower = middle + 1;
+        } else {
+            upper = middle;
+        }
+    }
+
+    lower
+}
+
+/// Creates a negative binomial distribution, with an error that describes the parameters if they are invalid.
+/// ```
+/// # use mc_sim::drop::*;
+/// # use mc_sim::stats::BlazeRodDistribution;
+/// // Blazes can't drop an average of more than one rod per fight in this model.
+/// let drop_list = vec![DropConfig::new(Item::BlazeRod, 1, 0, 3)];
+/// let error = BlazeRodDistribution::new(10, &drop_list).unwrap_err();
+/// assert!(error.to_string().contains("p = 1.5"));
+/// ```
+fn negative_binomial(r: f64, p: f64) -> Result<NegativeBinomial, McSimError> {
+    NegativeBinomial::new(r, p).map_err(|err| McSimError::InvalidDistribution {
+        reason: format!("expected r >= 0 and 0 <= p <= 1, got r = {}, p = {}", r, p),
+        err,
+    })
+}
+
+/// Wraps an ender pearl or blaze rod distribution, memoizing its luck for each number of failures.
+/// When many stream results share the same totals, this avoids computing the same CDF over and over.
+/// It can be shared between threads, such as when summarizing results in parallel.
+#[derive(Debug)]
+pub struct CachedDistribution<D> {
+    distribution: D,
+    luck: Mutex<HashMap<i64, f64>>,
+}
+
+impl<D> CachedDistribution<D> {
+    /// Creates a cached distribution, with nothing cached yet.
+    pub fn new(distribution: D) -> Self {
+        Self {
+            distribution,
+            luck: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The distribution that is being cached.
+    pub fn distribution(&self) -> &D {
+        &self.distribution
+    }
+
+    /// Gets the luck for a number of failures from the cache, or computes and caches it.
+    /// The luck is computed without holding the lock, so that other threads aren't held up by it.
+    fn cached_luck(&self, failures: i64, luck: impl FnOnce() -> f64) -> f64 {
+        let cached = self.luck.lock().unwrap().get(&failures).copied();
+
+        match cached {
+            Some(luck) => luck,
+            None => {
+                let luck = luck();
+                *self.luck.lock().unwrap().entry(failures).or_insert(luck)
+            }
+        }
+    }
+}
+
+impl CachedDistribution<EnderPearlDistribution> {
+    /// The luck of a number of barters, as in [luck](EnderPearlDistribution::luck).
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::stats::CachedDistribution;
+    /// let distribution = drop_list::barter_drop_list(220, 10).distribution().unwrap();
+    /// let cached = CachedDistribution::new(distribution);
+    ///
+    /// for (barters, successful) in [(937, 44), (400, 40), (937, 44), (399, 39)] {
+    ///     assert_eq!(cached.luck(barters, successful), distribution.luck(barters, successful));
+    /// }
+    /// ```
+    pub fn luck(&self, total_barters_made: u32, successful_barters: u32) -> f64 {
+        self.cached_luck(
+            total_barters_made as i64 - successful_barters as i64,
+            || {
+                self.distribution
+                    .luck(total_barters_made, successful_barters)
+            },
         )
-        .map_err(|_| McSimError::InvalidDistribution)
+    }
+}
+
+impl CachedDistribution<BlazeRodDistribution> {
+    /// The luck of a number of blaze fights, as in [luck](BlazeRodDistribution::luck).
+    pub fn luck(&self, total_blazes_killed: u32) -> f64 {
+        self.cached_luck(total_blazes_killed as i64, || {
+            self.distribution.luck(total_blazes_killed)
+        })
     }
 }
 
@@ -192,21 +791,35 @@ impl BlazeRodDistribution {
 /// ```
 pub fn item_drop_probability(drop_list: &[DropConfig], item: Item) -> f64 {
     let target = drop_list.iter().find(|d| d.item == item).unwrap();
-    target.weight as f64 / drop_list.iter().map(|d| d.weight as f64).sum::<f64>()
+    target.probability(drop_list.iter().map(|d| d.weight).sum())
 }
 
 /// Computes the mean number of items dropped for a given item on a drop list.
 /// Assumes that the drop list only has the item once in the list.
+/// The mean is that of the drop's [CountDistribution](crate::drop::CountDistribution). By default, drop counts are
+/// uniform over the inclusive range from the min to the max count, so the mean is the midpoint.
+/// This agrees with the counts that [DropSim](crate::drop::DropSim) produces.
 /// ```
-/// # use mc_sim::drop::Item;
+/// # use mc_sim::drop::*;
 /// # use mc_sim::drop_list;
 /// # use mc_sim::stats;
 /// assert_eq!(stats::item_drop_average(drop_list::blaze_drop_list(7).list(), Item::BlazeRod), 0.5);
 /// assert_eq!(stats::item_drop_average(drop_list::barter_drop_list(10, 10).list(), Item::EnderPearl), 6.0);
+///
+/// let drop_list = drop_list::barter_drop_list(10, 10);
+/// let mut drop_sim = DropSim::from_seed(drop_list.list_clone(), 7);
+/// let counts: Vec<u32> = (0..100000)
+///     .map(|_| drop_sim.get_drop())
+///     .filter(|drop| drop.item == Item::EnderPearl)
+///     .map(|drop| drop.count)
+///     .collect();
+///
+/// let mean = counts.iter().sum::<u32>() as f64 / counts.len() as f64;
+/// assert!((mean - stats::item_drop_average(drop_list.list(), Item::EnderPearl)).abs() < 0.05);
 /// ```
 pub fn item_drop_average(drop_list: &[DropConfig], item: Item) -> f64 {
     let target = drop_list.iter().find(|d| d.item == item).unwrap();
-    (target.max_count as f64 - target.min_count as f64) / 2.0 + target.min_count as f64
+    target.mean_count()
 }
 
 /// Provides the minimum and maximum amount that a drop of an item could provide from a drop list.
@@ -223,6 +836,1195 @@ pub fn item_drop_range(drop_list: &[DropConfig], item: Item) -> (u32, u32) {
     (target.min_count, target.max_count)
 }
 
+/// Estimates the successful barters implied by a target number of ender pearls, as the target over the mean count
+/// of an ender pearl drop on the drop list, rounded to the nearest barter.
+/// Only the barters that drop pearls count as successful, so the weight of ender pearls doesn't change this.
+/// Assumes that the drop list only has ender pearls once in the list.
+/// ```
+/// # use mc_sim::drop::Item;
+/// # use mc_sim::drop_list;
+/// # use mc_sim::stats;
+/// // Ender pearls drop 4 to 8 at a time on the 1.16.1 list, for a mean of 6, so 220 pearls is 36.7 barters.
+/// let drop_list = drop_list::barter_drop_list(220, 10);
+/// assert_eq!(stats::expected_successful_barters(220, drop_list.list()), 37);
+/// assert_eq!(stats::expected_successful_barters(0, drop_list.list()), 0);
+///
+/// // More barters are needed when each drops fewer pearls.
+/// let mut smaller = drop_list.list_clone();
+/// smaller.iter_mut().filter(|d| d.item == Item::EnderPearl).for_each(|d| d.max_count = 4);
+/// assert_eq!(stats::expected_successful_barters(220, &smaller), 55);
+/// ```
+pub fn expected_successful_barters(target_pearls: u32, drop_list: &[DropConfig]) -> u32 {
+    let pearls = drop_list
+        .iter()
+        .find(|d| d.item == Item::EnderPearl)
+        .unwrap();
+    (target_pearls as f64 / pearls.mean_count()).round() as u32
+}
+
+/// Computes the probability that a single drop from a drop list is a specific item with a count of at least one.
+/// Assumes that the drop list only has the item once in the list, and fails if it isn't on the list.
+/// ```
+/// # use mc_sim::drop::Item;
+/// # use mc_sim::drop_list;
+/// # use mc_sim::stats;
+/// assert_eq!(stats::item_success_probability(drop_list::blaze_drop_list(7).list(), Item::BlazeRod).unwrap(), 0.5);
+/// assert_eq!(stats::item_success_probability(drop_list::barter_drop_list(10, 10).list(), Item::EnderPearl).unwrap(), 20.0 / 423.0);
+/// assert!(stats::item_success_probability(drop_list::blaze_drop_list(7).list(), Item::EnderPearl).is_err());
+/// ```
+pub fn item_success_probability(drop_list: &[DropConfig], item: Item) -> Result<f64, McSimError> {
+    let target = drop_list
+        .iter()
+        .find(|d| d.item == item)
+        .ok_or(McSimError::InvalidDropList(
+            "the item isn't on the drop list",
+        ))?;
+    let nothing = target
+        .count_dist
+        .probability(0, target.min_count, target.max_count);
+
+    Ok(item_drop_probability(drop_list, item) * (1.0 - nothing))
+}
+
+/// Writes a list of stream results out as a JSON array.
+/// This is intended for loading simulation results into other tools, such as pandas or a web dashboard.
+/// ```
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let results = vec![
+///     StreamResults::new(&goals, 40, 30, 5, 14),
+///     StreamResults::new(&goals, 25, 20, 4, 14),
+/// ];
+///
+/// let mut json = Vec::new();
+/// stats::write_results_json(&results, &mut json).unwrap();
+/// let read_back = stats::read_results_json(json.as_slice()).unwrap();
+///
+/// assert_eq!(read_back, results);
+/// assert_eq!(read_back[1].total_barters, 25);
+/// ```
+pub fn write_results_json<W: Write>(
+    results: &[StreamResults],
+    writer: W,
+) -> Result<(), McSimError> {
+    serde_json::to_writer(writer, results).map_err(McSimError::from)
+}
+
+/// Reads a list of stream results from a JSON array, as written by [write_results_json].
+pub fn read_results_json<R: Read>(reader: R) -> Result<Vec<StreamResults>, McSimError> {
+    serde_json::from_reader(reader).map_err(McSimError::from)
+}
+
+/// A metric of stream results that can be queried over a set of results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Metric {
+    TotalBarters,
+    TotalFights,
+}
+
+impl Metric {
+    /// Gets the value of this metric for some stream results.
+    pub fn value(&self, results: &StreamResults) -> u32 {
+        match self {
+            Metric::TotalBarters => results.total_barters,
+            Metric::TotalFights => results.total_fights,
+        }
+    }
+
+    /// Gets the value of this metric for each of a set of stream results.
+    fn values(&self, results: &[StreamResults]) -> Vec<u32> {
+        results.iter().map(|r| self.value(r)).collect()
+    }
+}
+
+/// Summary statistics for a single metric over a set of stream results.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetricSummary {
+    pub mean: f64,
+    pub median: f64,
+    pub min: u32,
+    pub max: u32,
+    pub std_dev: f64,
+}
+
+impl MetricSummary {
+    /// Summarizes a list of values. The values must not be empty.
+    fn from_values(mut values: Vec<u32>) -> Self {
+        values.sort_unstable();
+
+        let len = values.len();
+        let mean = values.iter().map(|v| *v as f64).sum::<f64>() / len as f64;
+        let variance = values
+            .iter()
+            .map(|v| (*v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / len as f64;
+        let median = (values[(len - 1) / 2] as f64 + values[len / 2] as f64) / 2.0;
+
+        Self {
+            mean,
+            median,
+            min: values[0],
+            max: values[len - 1],
+            std_dev: variance.sqrt(),
+        }
+    }
+}
+
+/// Accumulates the mean, variance, min and max of values one at a time, without keeping the values.
+/// This uses Welford's online algorithm, so it can summarize any number of stream results in constant memory.
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats::{self, RunningStats};
+/// # use mc_sim::stream::StreamResults;
+/// let mut stats = RunningStats::new();
+/// for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].iter() {
+///     stats.push(*value);
+/// }
+///
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), 5.0);
+/// assert_eq!(stats.variance(), 4.0);
+/// assert_eq!((stats.min(), stats.max()), (2.0, 9.0));
+///
+/// // It agrees with summarizing all of the results at once.
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let results = vec![
+///     StreamResults::new(&goals, 10, 25, 4, 14),
+///     StreamResults::new(&goals, 40, 31, 5, 14),
+///     StreamResults::new(&goals, 20, 29, 4, 14),
+///     StreamResults::new(&goals, 30, 27, 5, 14),
+/// ];
+///
+/// let mut barters = RunningStats::new();
+/// results.iter().for_each(|r| barters.push(r.total_barters as f64));
+///
+/// let summary = stats::summarize(&results, &drop_list::barter_drop_list(20, 10), &drop_list::blaze_drop_list(14)).unwrap();
+/// assert!((barters.mean() - summary.barters.mean).abs() < 1e-9);
+/// assert!((barters.variance().sqrt() - summary.barters.std_dev).abs() < 1e-9);
+/// assert_eq!(barters.max(), summary.barters.max as f64);
+///
+/// assert!(RunningStats::new().mean().is_nan());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    sum_of_squares: f64,
+    min: f64,
+    max: f64,
+}
+
+impl RunningStats {
+    /// Creates an accumulator with no values.
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            sum_of_squares: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Adds a value.
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.sum_of_squares += delta * (value - self.mean);
+
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// The number of values that have been added.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The mean of the values, or `NaN` if there are none.
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.mean
+        }
+    }
+
+    /// The population variance of the values, like [MetricSummary], or `NaN` if there are none.
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.sum_of_squares / self.count as f64
+        }
+    }
+
+    /// The smallest value, or infinity if there are none.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// The largest value, or negative infinity if there are none.
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Default for RunningStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A summary of a set of simulated stream results.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ResultsSummary {
+    pub streams: usize,
+    pub barters: MetricSummary,
+    pub fights: MetricSummary,
+    pub luckiest: f64,
+}
+
+/// Summarizes a set of stream results, giving statistics for the barters and fights,
+/// and the luck of the luckiest stream in the set.
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let results = vec![
+///     StreamResults::new(&goals, 10, 25, 4, 14),
+///     StreamResults::new(&goals, 40, 31, 5, 14),
+///     StreamResults::new(&goals, 20, 29, 4, 14),
+///     StreamResults::new(&goals, 30, 27, 5, 14),
+/// ];
+///
+/// let barter_drop_list = drop_list::barter_drop_list(20, 10);
+/// let blaze_drop_list = drop_list::blaze_drop_list(14);
+/// let summary = stats::summarize(&results, &barter_drop_list, &blaze_drop_list).unwrap();
+///
+/// assert_eq!(summary.streams, 4);
+/// assert_eq!(summary.barters.mean, 25.0);
+/// assert_eq!(summary.barters.median, 25.0);
+/// assert_eq!(summary.barters.min, 10);
+/// assert_eq!(summary.barters.max, 40);
+/// assert_eq!(summary.barters.std_dev, 125.0f64.sqrt());
+/// assert_eq!(summary.fights.mean, 28.0);
+/// assert_eq!(summary.fights.std_dev, 5.0f64.sqrt());
+/// assert_eq!(summary.luckiest, results[0].luck(&barter_drop_list, &blaze_drop_list));
+///
+/// assert!(stats::summarize(&[], &barter_drop_list, &blaze_drop_list).is_err());
+/// ```
+pub fn summarize(
+    results: &[StreamResults],
+    barter_drop_list: &DropList<EnderPearlDistribution>,
+    blaze_drop_list: &DropList<BlazeRodDistribution>,
+) -> Result<ResultsSummary, McSimError> {
+    if results.is_empty() {
+        return Err(McSimError::EmptyResults);
+    }
+
+    let (pearls, rods) = cached_distributions(barter_drop_list, blaze_drop_list);
+    let luckiest = results
+        .iter()
+        .map(|r| r.cached_luck(&pearls, &rods))
+        .fold(1.0, f64::min);
+
+    Ok(ResultsSummary {
+        streams: results.len(),
+        barters: MetricSummary::from_values(Metric::TotalBarters.values(results)),
+        fights: MetricSummary::from_values(Metric::TotalFights.values(results)),
+        luckiest,
+    })
+}
+
+/// A comparison of the stream results of two scenarios. See: [compare]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ComparisonReport {
+    pub streams_a: usize,
+    pub streams_b: usize,
+    /// The mean total barters of scenario b minus that of scenario a.
+    pub barters_difference: f64,
+    /// The mean total fights of scenario b minus that of scenario a.
+    pub fights_difference: f64,
+    /// Welch's t statistic for the difference in mean total barters.
+    pub barters_t: f64,
+    /// The two-sided p-value of the difference in mean total barters, under Welch's t-test.
+    pub barters_p_value: f64,
+}
+
+/// Compares the stream results of two scenarios, such as two drop lists or two sets of goals.
+/// The mean total barters are compared with Welch's t-test, so each scenario needs at least two results.
+/// ```
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let scenario = |barters: &[u32], fights: u32| -> Vec<StreamResults> {
+///     barters.iter().map(|b| StreamResults::new(&goals, *b, fights, 4, 14)).collect()
+/// };
+/// let a = scenario(&[98, 102, 95, 105, 100, 97, 103, 99, 101, 100], 30);
+/// let b = scenario(&[148, 152, 145, 155, 150, 147, 153, 149, 151, 150], 32);
+///
+/// let report = stats::compare(&a, &b).unwrap();
+/// assert_eq!(report.streams_a, 10);
+/// assert_eq!(report.barters_difference, 50.0);
+/// assert_eq!(report.fights_difference, 2.0);
+/// assert!(report.barters_t > 0.0);
+/// assert!(report.barters_p_value < 1e-6);
+///
+/// // A scenario compared with itself shows no difference.
+/// let same = stats::compare(&a, &a).unwrap();
+/// assert_eq!(same.barters_difference, 0.0);
+/// assert!((same.barters_p_value - 1.0).abs() < 1e-9);
+///
+/// assert!(stats::compare(&a[..1], &b).is_err());
+/// ```
+pub fn compare(
+    scenario_a: &[StreamResults],
+    scenario_b: &[StreamResults],
+) -> Result<ComparisonReport, McSimError> {
+    for scenario in &[scenario_a, scenario_b] {
+        if scenario.len() < 2 {
+            return Err(McSimError::NotEnoughResults(2, scenario.len()));
+        }
+    }
+
+    let (mean_a, variance_a) = sample_mean_variance(&Metric::TotalBarters.values(scenario_a));
+    let (mean_b, variance_b) = sample_mean_variance(&Metric::TotalBarters.values(scenario_b));
+    let (fights_a, _) = sample_mean_variance(&Metric::TotalFights.values(scenario_a));
+    let (fights_b, _) = sample_mean_variance(&Metric::TotalFights.values(scenario_b));
+
+    let len_a = scenario_a.len() as f64;
+    let len_b = scenario_b.len() as f64;
+    let error_a = variance_a / len_a;
+    let error_b = variance_b / len_b;
+    let standard_error = (error_a + error_b).sqrt();
+    let difference = mean_b - mean_a;
+
+    // With no variance in either scenario, any difference at all is certain.
+    let (barters_t, barters_p_value) = if standard_error == 0.0 {
+        if difference == 0.0 {
+            (0.0, 1.0)
+        } else {
+            (difference.signum() * f64::INFINITY, 0.0)
+        }
+    } else {
+        let t = difference / standard_error;
+        let freedom = (error_a + error_b).powi(2)
+            / (error_a.powi(2) / (len_a - 1.0) + error_b.powi(2) / (len_b - 1.0));
+        let distribution =
+            StudentsT::new(0.0, 1.0, freedom).map_err(|err| McSimError::InvalidDistribution {
+                reason: "Welch's t-test".to_string(),
+                err,
+            })?;
+        (t, 2.0 * (1.0 - distribution.cdf(t.abs())))
+    };
+
+    Ok(ComparisonReport {
+        streams_a: scenario_a.len(),
+        streams_b: scenario_b.len(),
+        barters_difference: difference,
+        fights_difference: fights_b - fights_a,
+        barters_t,
+        barters_p_value: barters_p_value.clamp(0.0, 1.0),
+    })
+}
+
+/// The mean and sample (n - 1) variance of a list of values.
+fn sample_mean_variance(values: &[u32]) -> (f64, f64) {
+    let len = values.len() as f64;
+    let mean = values.iter().map(|v| *v as f64).sum::<f64>() / len;
+    let sum_of_squares = values
+        .iter()
+        .map(|v| (*v as f64 - mean).powi(2))
+        .sum::<f64>();
+    (mean, sum_of_squares / (len - 1.0))
+}
+
+/// Finds the luckiest of a set of stream results, as ranked by a metric where a lower value is luckier.
+/// Results with a `NaN` metric are never the luckiest, and ties are broken by the fewest barters, then the fewest fights.
+/// ```
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let results = vec![
+///     StreamResults::new(&goals, 10, 25, 4, 14),
+///     StreamResults::new(&goals, 40, 31, 5, 14),
+///     StreamResults::new(&goals, 20, 29, 4, 14),
+///     StreamResults::new(&goals, 20, 27, 5, 14),
+/// ];
+///
+/// // The first result has a NaN metric, and the last two tie.
+/// let metric = |r: &StreamResults| if r.total_barters == 10 { f64::NAN } else { (r.total_barters / 10) as f64 };
+/// assert_eq!(stats::luckiest_by(&results, metric), Some(&results[3]));
+/// assert_eq!(stats::luckiest_by(&results[..1], metric), None);
+/// ```
+pub fn luckiest_by<'a>(
+    results: impl IntoIterator<Item = &'a StreamResults>,
+    metric: impl Fn(&StreamResults) -> f64,
+) -> Option<&'a StreamResults> {
+    results
+        .into_iter()
+        .map(|results| (metric(results), results))
+        .filter(|(luck, _)| !luck.is_nan())
+        .min_by(|(lhs_luck, lhs), (rhs_luck, rhs)| {
+            lhs_luck
+                .total_cmp(rhs_luck)
+                .then(lhs.total_barters.cmp(&rhs.total_barters))
+                .then(lhs.total_fights.cmp(&rhs.total_fights))
+        })
+        .map(|(_, results)| results)
+}
+
+/// An estimate of the probability of a stream being at least as lucky as some luck,
+/// from the proportion of simulated streams that were, with a Wilson score interval around it.
+/// See: [Simulation::run_to_confidence](crate::sim::Simulation::run_to_confidence)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LuckEstimate {
+    /// The number of streams at least as lucky.
+    pub lucky_streams: u64,
+    /// The number of streams simulated.
+    pub streams: u64,
+    /// The proportion of streams that were at least as lucky.
+    pub probability: f64,
+    /// The lower bound of the interval.
+    pub lower: f64,
+    /// The upper bound of the interval.
+    pub upper: f64,
+}
+
+impl LuckEstimate {
+    /// Estimates the probability from a number of lucky streams out of those simulated,
+    /// with a Wilson score interval at a confidence level (strictly between 0 and 1).
+    /// ```
+    /// # use mc_sim::stats::LuckEstimate;
+    /// let estimate = LuckEstimate::wilson(50, 100, 0.95).unwrap();
+    /// assert_eq!(estimate.probability, 0.5);
+    /// assert!((estimate.lower - 0.4038).abs() < 1e-4);
+    /// assert!((estimate.upper - 0.5962).abs() < 1e-4);
+    ///
+    /// // Unlike the normal approximation, the interval is still useful when no streams were lucky.
+    /// let estimate = LuckEstimate::wilson(0, 100, 0.95).unwrap();
+    /// assert_eq!(estimate.lower, 0.0);
+    /// assert!(estimate.upper > 0.0);
+    ///
+    /// assert!(LuckEstimate::wilson(50, 100, 1.0).is_err());
+    /// ```
+    pub fn wilson(lucky_streams: u64, streams: u64, confidence: f64) -> Result<Self, McSimError> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(McSimError::InvalidConfidence(confidence));
+        }
+
+        if streams == 0 {
+            return Ok(Self {
+                lucky_streams,
+                streams,
+                probability: 0.0,
+                lower: 0.0,
+                upper: 1.0,
+            });
+        }
+
+        let z = Normal::new(0.0, 1.0)
+            .unwrap()
+            .inverse_cdf(1.0 - (1.0 - confidence) / 2.0);
+        let n = streams as f64;
+        let p = lucky_streams as f64 / n;
+
+        let denominator = 1.0 + z * z / n;
+        let centre = (p + z * z / (2.0 * n)) / denominator;
+        let half_width = z * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt() / denominator;
+
+        Ok(Self {
+            lucky_streams,
+            streams,
+            probability: p,
+            lower: (centre - half_width).max(0.0),
+            upper: (centre + half_width).min(1.0),
+        })
+    }
+
+    /// The half-width of the interval relative to the estimated probability.
+    /// This is infinite when no streams were lucky, since the estimate is then zero.
+    /// ```
+    /// # use mc_sim::stats::LuckEstimate;
+    /// let estimate = LuckEstimate::wilson(500, 1000, 0.95).unwrap();
+    /// assert!(estimate.relative_error() < 0.1);
+    /// assert_eq!(LuckEstimate::wilson(0, 1000, 0.95).unwrap().relative_error(), f64::INFINITY);
+    /// ```
+    pub fn relative_error(&self) -> f64 {
+        if self.probability == 0.0 {
+            f64::INFINITY
+        } else {
+            (self.upper - self.lower) / 2.0 / self.probability
+        }
+    }
+}
+
+/// Finds the `n` luckiest of a set of stream results by their combined luck, from luckiest to least lucky.
+/// A heap of the `n` luckiest so far is kept rather than sorting all of the results, which is much faster
+/// when only a few of many results are needed. Like [luckiest_by], `NaN` luck is never included
+/// and ties are broken by the fewest barters, then the fewest fights.
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let results: Vec<StreamResults> = (0..100)
+///     .map(|i| StreamResults::new(&goals, 20 + (i * 37) % 50, 14 + (i * 11) % 30, 4, 14))
+///     .collect();
+/// let (barter_list, blaze_list) = (drop_list::barter_drop_list(20, 10), drop_list::blaze_drop_list(14));
+///
+/// let luckiest = stats::luckiest_n(&results, 10, &barter_list, &blaze_list);
+/// assert_eq!(luckiest.len(), 10);
+/// assert!(luckiest.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+///
+/// let mut lucks: Vec<f64> = results.iter().map(|r| r.luck(&barter_list, &blaze_list)).collect();
+/// lucks.sort_by(|a, b| a.total_cmp(b));
+/// assert!(luckiest.iter().zip(&lucks).all(|((luck, _), expected)| luck == expected));
+///
+/// assert_eq!(stats::luckiest_n(&results[..3], 10, &barter_list, &blaze_list).len(), 3);
+/// assert!(stats::luckiest_n(&results, 0, &barter_list, &blaze_list).is_empty());
+/// ```
+pub fn luckiest_n(
+    results: &[StreamResults],
+    n: usize,
+    barter_drop_list: &DropList<EnderPearlDistribution>,
+    blaze_drop_list: &DropList<BlazeRodDistribution>,
+) -> Vec<(f64, StreamResults)> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // A max-heap, so the least lucky of the luckiest so far is on top, ready to be replaced.
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+    let (pearls, rods) = cached_distributions(barter_drop_list, blaze_drop_list);
+
+    for results in results {
+        let luck = results.cached_luck(&pearls, &rods);
+
+        if luck.is_nan() {
+            continue;
+        }
+
+        heap.push(RankedResults { luck, results });
+
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|ranked| (ranked.luck, ranked.results.clone()))
+        .collect()
+}
+
+/// Stream results ranked by their luck, then by their barters and fights. See: [luckiest_n]
+struct RankedResults<'a> {
+    luck: f64,
+    results: &'a StreamResults,
+}
+
+impl Ord for RankedResults<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.luck
+            .total_cmp(&other.luck)
+            .then(self.results.total_barters.cmp(&other.results.total_barters))
+            .then(self.results.total_fights.cmp(&other.results.total_fights))
+    }
+}
+
+impl PartialOrd for RankedResults<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for RankedResults<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RankedResults<'_> {}
+
+/// Gets the value of a metric at a percentile `p` (from 0 to 1) over a set of stream results.
+/// Values between two results are linearly interpolated.
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats::{self, Metric};
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let results = vec![
+///     StreamResults::new(&goals, 10, 25, 4, 14),
+///     StreamResults::new(&goals, 40, 31, 5, 14),
+///     StreamResults::new(&goals, 20, 29, 4, 14),
+///     StreamResults::new(&goals, 30, 27, 5, 14),
+/// ];
+///
+/// let barter_drop_list = drop_list::barter_drop_list(20, 10);
+/// let blaze_drop_list = drop_list::blaze_drop_list(14);
+/// let summary = stats::summarize(&results, &barter_drop_list, &blaze_drop_list).unwrap();
+///
+/// assert_eq!(stats::percentile(&results, Metric::TotalBarters, 0.5).unwrap(), summary.barters.median);
+/// assert_eq!(stats::percentile(&results, Metric::TotalFights, 0.5).unwrap(), summary.fights.median);
+/// assert_eq!(stats::percentile(&results, Metric::TotalBarters, 0.0).unwrap(), 10.0);
+/// assert_eq!(stats::percentile(&results, Metric::TotalBarters, 1.0).unwrap(), 40.0);
+///
+/// assert!(stats::percentile(&[], Metric::TotalBarters, 0.5).is_err());
+/// assert!(stats::percentile(&results, Metric::TotalBarters, 1.5).is_err());
+/// ```
+pub fn percentile(results: &[StreamResults], metric: Metric, p: f64) -> Result<f64, McSimError> {
+    if results.is_empty() {
+        return Err(McSimError::EmptyResults);
+    }
+
+    if !(0.0..=1.0).contains(&p) {
+        return Err(McSimError::InvalidPercentile(p));
+    }
+
+    let mut values = metric.values(results);
+    values.sort_unstable();
+
+    let rank = p * (values.len() - 1) as f64;
+    let (lower, upper) = (values[rank.floor() as usize], values[rank.ceil() as usize]);
+
+    Ok(lower as f64 + (upper as f64 - lower as f64) * rank.fract())
+}
+
+/// Estimates the luck of an observed stream empirically, as the proportion of simulated stream results
+/// that were at least as lucky as the observed stream. This is the Monte Carlo counterpart to
+/// [luck](StreamResults::luck), and should closely agree with it for a large enough set of results.
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let results = vec![
+///     StreamResults::new(&goals, 10, 25, 4, 14),
+///     StreamResults::new(&goals, 40, 41, 5, 14),
+///     StreamResults::new(&goals, 20, 29, 4, 14),
+///     StreamResults::new(&goals, 30, 37, 5, 14),
+/// ];
+///
+/// let barter_drop_list = drop_list::barter_drop_list(20, 10);
+/// let blaze_drop_list = drop_list::blaze_drop_list(14);
+/// let observed = StreamResults::new(&goals, 25, 30, 4, 14);
+///
+/// let luck = stats::empirical_luck(&results, &observed, &barter_drop_list, &blaze_drop_list);
+/// assert_eq!(luck.unwrap(), 0.5);
+/// ```
+pub fn empirical_luck(
+    results: &[StreamResults],
+    observed: &StreamResults,
+    barter_drop_list: &DropList<EnderPearlDistribution>,
+    blaze_drop_list: &DropList<BlazeRodDistribution>,
+) -> Result<f64, McSimError> {
+    if results.is_empty() {
+        return Err(McSimError::EmptyResults);
+    }
+
+    #[cfg(feature = "threaded")]
+    let streams = results.par_iter();
+    #[cfg(not(feature = "threaded"))]
+    let streams = results.iter();
+
+    let observed_luck = observed.luck(barter_drop_list, blaze_drop_list);
+    let (pearls, rods) = cached_distributions(barter_drop_list, blaze_drop_list);
+    let as_lucky = streams
+        .filter(|r| r.cached_luck(&pearls, &rods) <= observed_luck)
+        .count();
+
+    Ok(as_lucky as f64 / results.len() as f64)
+}
+
+/// Computes the luck of every stream result, in parallel with the `threaded` feature. See: [luck](StreamResults::luck)
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let results: Vec<StreamResults> = (0..1000)
+///     .map(|i| StreamResults::new(&goals, 20 + i % 50, 20 + i % 20, 2 + i % 3, 14))
+///     .collect();
+/// let (barter_list, blaze_list) = (drop_list::barter_drop_list(20, 10), drop_list::blaze_drop_list(14));
+///
+/// let serial: Vec<f64> = results.iter().map(|r| r.luck(&barter_list, &blaze_list)).collect();
+/// assert_eq!(stats::luck_all(&results, &barter_list, &blaze_list), serial);
+/// ```
+pub fn luck_all(
+    results: &[StreamResults],
+    barter_drop_list: &DropList<EnderPearlDistribution>,
+    blaze_drop_list: &DropList<BlazeRodDistribution>,
+) -> Vec<f64> {
+    #[cfg(feature = "threaded")]
+    let streams = results.par_iter();
+    #[cfg(not(feature = "threaded"))]
+    let streams = results.iter();
+
+    let (pearls, rods) = cached_distributions(barter_drop_list, blaze_drop_list);
+    streams.map(|r| r.cached_luck(&pearls, &rods)).collect()
+}
+
+/// Caches the distributions of the drop lists, for computing the luck of many stream results that share totals.
+/// See: [cached_luck](StreamResults::cached_luck)
+fn cached_distributions(
+    barter_drop_list: &DropList<EnderPearlDistribution>,
+    blaze_drop_list: &DropList<BlazeRodDistribution>,
+) -> (
+    CachedDistribution<EnderPearlDistribution>,
+    CachedDistribution<BlazeRodDistribution>,
+) {
+    (
+        CachedDistribution::new(barter_drop_list.distribution().unwrap()),
+        CachedDistribution::new(blaze_drop_list.distribution().unwrap()),
+    )
+}
+
+/// Computes the combined luck of a stream directly from its totals, using the 1.16.1 drop lists.
+/// See: [luck](StreamResults::luck)
+/// ```
+/// # use mc_sim::stats;
+/// // The 22 run example: 937 barters (4 of them successful) and 308 fights for 10 pearls and 7 rods per run.
+/// let luck = stats::stream_luck(937, 4, 308, 220, 10, 154);
+/// assert_eq!(luck, 0.2622158704150333);
+/// ```
+pub fn stream_luck(
+    total_barters: u32,
+    successful_barters: u32,
+    total_fights: u32,
+    target_pearls_total: u32,
+    target_pearls_per_run: u32,
+    target_rods: u32,
+) -> f64 {
+    // Like the luck of stream results, an item that wasn't targeted has nothing to be lucky about.
+    let pearl_luck = if target_pearls_total == 0 {
+        1.0
+    } else {
+        drop_list::barter_drop_list(target_pearls_total, target_pearls_per_run)
+            .distribution()
+            .unwrap()
+            .luck(total_barters, successful_barters)
+    };
+
+    let rod_luck = if target_rods == 0 {
+        1.0
+    } else {
+        drop_list::blaze_drop_list(target_rods)
+            .distribution()
+            .unwrap()
+            .luck(total_fights)
+    };
+
+    pearl_luck * rod_luck
+}
+
+/// The combined luck of Dream's runs as they were observed, using the 1.16.1 drop lists.
+/// The pearls and rods were counted over different runs, so each is given to
+/// [from_observed](StreamResults::from_observed) with its own number of runs: (at worst) 239 barters with 39 pearl
+/// drops over the 17 runs that got 10+ pearls, and 305 blaze fights for 211 rods over all 22 runs.
+/// The combined luck is the pearl luck times the rod luck.
+/// ```
+/// # use mc_sim::stats;
+/// assert_eq!(stats::dream_luck(), 0.000000000000000000005902209912719003);
+/// ```
+pub fn dream_luck() -> f64 {
+    let pearl_runs = StreamResults::from_observed(17, 239, 0, 39, 0, 170, 10, 0);
+    let rod_runs = StreamResults::from_observed(22, 0, 305, 0, 211, 0, 0, 211);
+
+    pearl_runs.pearl_luck(&drop_list::barter_drop_list(170, 10))
+        * rod_runs.rod_luck(&drop_list::blaze_drop_list(211))
+}
+
+/// Computes the combined luck of a set of runs, from the totals of each run, using the 1.16.1 drop lists.
+/// Each run is given as `(barters, successful_barters, fights, target_rods, target_pearls, target_pearls_per_run)`,
+/// where the pearls targeted per run is that of the distribution, such as 10 when every run is cut off at 10 pearls.
+/// The runs are combined into a single stream, with the average pearls targeted per run. See: [stream_luck]
+/// Fails if any of the totals don't fit in a `u32`.
+/// ```
+/// # use mc_sim::stats;
+/// // The 22 run example, laid out per run: 21 runs without a successful barter, and one with all 4.
+/// let mut runs = vec![(42, 0, 14, 7, 10, 10); 21];
+/// runs.push((55, 4, 14, 7, 10, 10));
+///
+/// assert_eq!(stats::luck_for_runs(&runs).unwrap(), stats::stream_luck(937, 4, 308, 220, 10, 154));
+///
+/// assert!(stats::luck_for_runs(&[(u32::MAX, 0, 14, 7, 10, 10), (1, 0, 14, 7, 10, 10)]).is_err());
+/// ```
+pub fn luck_for_runs(runs: &[(u32, u32, u32, u32, u32, u32)]) -> Result<f64, McSimError> {
+    let number_of_runs = checked_total(runs.iter().map(|_| 1), "runs")?;
+    let barters = checked_total(runs.iter().map(|run| run.0), "barters")?;
+    let successful_barters = checked_total(runs.iter().map(|run| run.1), "successful barters")?;
+    let fights = checked_total(runs.iter().map(|run| run.2), "fights")?;
+    let target_rods = checked_total(runs.iter().map(|run| run.3), "target rods")?;
+    let target_pearls = checked_total(runs.iter().map(|run| run.4), "target pearls")?;
+    let target_pearls_per_run =
+        checked_total(runs.iter().map(|run| run.5), "target pearls per run")?;
+
+    Ok(stream_luck(
+        barters,
+        successful_barters,
+        fights,
+        target_pearls,
+        target_pearls_per_run
+            .checked_div(number_of_runs)
+            .unwrap_or(0),
+        target_rods,
+    ))
+}
+
+/// Combines the results of two simulations, such as those run on different machines.
+/// ```
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let a = vec![StreamResults::new(&goals, 40, 30, 5, 14)];
+/// let b = vec![StreamResults::new(&goals, 25, 20, 4, 14), StreamResults::new(&goals, 30, 22, 4, 14)];
+/// assert_eq!(stats::merge_results(a, b).len(), 3);
+/// ```
+pub fn merge_results(mut a: Vec<StreamResults>, mut b: Vec<StreamResults>) -> Vec<StreamResults> {
+    a.append(&mut b);
+    a
+}
+
+/// Histograms of the total barters and total fights of simulated streams.
+/// Unlike the results themselves, these stay small no matter how many streams are recorded,
+/// and histograms from separate simulations can be merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationHistogram {
+    barters: BTreeMap<u32, u64>,
+    fights: BTreeMap<u32, u64>,
+    total: u64,
+}
+
+impl SimulationHistogram {
+    /// Creates an empty histogram.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a histogram from a set of stream results.
+    pub fn from_results(results: &[StreamResults]) -> Self {
+        let mut histogram = Self::new();
+
+        for result in results {
+            histogram.record(result);
+        }
+
+        histogram
+    }
+
+    /// Records a stream's results in the histogram.
+    pub fn record(&mut self, results: &StreamResults) {
+        *self.barters.entry(results.total_barters).or_insert(0) += 1;
+        *self.fights.entry(results.total_fights).or_insert(0) += 1;
+        self.total += 1;
+    }
+
+    /// Adds the counts of another histogram to this one, bucket by bucket.
+    /// ```
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stats::SimulationHistogram;
+    /// # use mc_sim::stream::StreamResults;
+    /// let goals = vec![RunGoals::new(10, 7); 2];
+    /// let mut a = SimulationHistogram::from_results(&[
+    ///     StreamResults::new(&goals, 40, 30, 5, 14),
+    ///     StreamResults::new(&goals, 25, 20, 4, 14),
+    /// ]);
+    /// let b = SimulationHistogram::from_results(&[
+    ///     StreamResults::new(&goals, 40, 20, 5, 14),
+    /// ]);
+    ///
+    /// a.merge(&b);
+    /// assert_eq!(a.total(), 3);
+    /// assert_eq!(a.barters()[&40], 2);
+    /// assert_eq!(a.barters()[&25], 1);
+    /// assert_eq!(a.fights()[&20], 2);
+    /// assert_eq!(a.fights()[&30], 1);
+    /// ```
+    pub fn merge(&mut self, other: &SimulationHistogram) {
+        for (&barters, &count) in &other.barters {
+            *self.barters.entry(barters).or_insert(0) += count;
+        }
+
+        for (&fights, &count) in &other.fights {
+            *self.fights.entry(fights).or_insert(0) += count;
+        }
+
+        self.total += other.total;
+    }
+
+    /// The number of streams recorded for each total number of barters.
+    pub fn barters(&self) -> &BTreeMap<u32, u64> {
+        &self.barters
+    }
+
+    /// The number of streams recorded for each total number of fights.
+    pub fn fights(&self) -> &BTreeMap<u32, u64> {
+        &self.fights
+    }
+
+    /// The total number of streams recorded.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+/// The running total of the frequencies of a histogram's buckets, in the order they're given.
+/// This is the empirical cdf when the buckets are sorted, so it never decreases and ends at 1.0.
+/// ```
+/// # use mc_sim::stats;
+/// let cumulative = stats::cumulative_frequencies(&[1, 3, 0, 4]);
+/// assert_eq!(cumulative, vec![0.125, 0.5, 0.5, 1.0]);
+/// assert!(cumulative.windows(2).all(|pair| pair[0] <= pair[1]));
+/// assert!(stats::cumulative_frequencies(&[]).is_empty());
+/// ```
+pub fn cumulative_frequencies(counts: &[u64]) -> Vec<f64> {
+    let total: u64 = counts.iter().sum();
+
+    counts
+        .iter()
+        .scan(0, |running, count| {
+            *running += count;
+            Some(*running as f64 / total as f64)
+        })
+        .collect()
+}
+
+/// A joint histogram over the total barters and total fights of simulated streams.
+/// Like [SimulationHistogram], this stays small no matter how many streams are recorded, and can be merged.
+/// It serializes as a list of its [JointBucket]s, sorted by barters and then by fights.
+/// ```
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats::JointHistogram;
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let mut a = JointHistogram::new();
+/// a.record(&StreamResults::new(&goals, 40, 30, 5, 14));
+/// a.record(&StreamResults::new(&goals, 25, 20, 4, 14));
+///
+/// let mut b = JointHistogram::new();
+/// b.record(&StreamResults::new(&goals, 40, 30, 4, 14));
+/// b.record(&StreamResults::new(&goals, 40, 28, 5, 14));
+///
+/// a.merge(&b);
+/// assert_eq!(a.total(), 4);
+/// assert_eq!(a.count((40, 30)), 2);
+/// assert_eq!(a.frequency((40, 30)), 0.5);
+/// assert_eq!(a.frequency((25, 20)), 0.25);
+/// assert_eq!(a.frequency((10, 10)), 0.0);
+///
+/// let json = serde_json::to_string(&a).unwrap();
+/// assert!(json.starts_with(r#"[{"barters":25,"fights":20,"count":1}"#));
+/// assert_eq!(serde_json::from_str::<JointHistogram>(&json).unwrap(), a);
+///
+/// let mut writer = csv::Writer::from_writer(vec![]);
+/// for bucket in a.buckets() {
+///     writer.serialize(bucket).unwrap();
+/// }
+/// let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+/// assert_eq!(csv.lines().count(), 4);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "Vec<JointBucket>", from = "Vec<JointBucket>")]
+pub struct JointHistogram {
+    counts: BTreeMap<(u32, u32), u64>,
+    total: u64,
+}
+
+/// A bucket of a [JointHistogram], with the number of streams that had exactly these totals.
+/// The frequency and estimated probability are only filled in by [joint_distribution].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JointBucket {
+    pub barters: u32,
+    pub fights: u32,
+    pub count: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_probability: Option<f64>,
+}
+
+impl JointHistogram {
+    /// Creates an empty histogram.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a histogram from a set of stream results, such as those from a simulation.
+    /// ```
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stats::JointHistogram;
+    /// # use mc_sim::stream::StreamResults;
+    /// let goals = vec![RunGoals::new(10, 7); 2];
+    /// let results = vec![
+    ///     StreamResults::new(&goals, 40, 30, 5, 14),
+    ///     StreamResults::new(&goals, 25, 20, 4, 14),
+    ///     StreamResults::new(&goals, 40, 30, 4, 14),
+    /// ];
+    ///
+    /// let histogram = JointHistogram::from_results(&results);
+    /// assert_eq!(histogram.total(), results.len() as u64);
+    /// assert_eq!(histogram.count((40, 30)), 2);
+    /// assert_eq!(JointHistogram::from(results.as_slice()), histogram);
+    /// ```
+    pub fn from_results(results: &[StreamResults]) -> Self {
+        let mut histogram = Self::new();
+
+        for result in results {
+            histogram.record(result);
+        }
+
+        histogram
+    }
+
+    /// Records a stream's results in the histogram.
+    pub fn record(&mut self, results: &StreamResults) {
+        *self
+            .counts
+            .entry((results.total_barters, results.total_fights))
+            .or_insert(0) += 1;
+        self.total += 1;
+    }
+
+    /// Adds the counts of another histogram to this one, bucket by bucket.
+    pub fn merge(&mut self, other: &JointHistogram) {
+        for (&key, &count) in &other.counts {
+            *self.counts.entry(key).or_insert(0) += count;
+        }
+
+        self.total += other.total;
+    }
+
+    /// The total number of streams recorded.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The number of streams recorded with a total number of barters and fights.
+    pub fn count(&self, key: (u32, u32)) -> u64 {
+        self.counts.get(&key).copied().unwrap_or(0)
+    }
+
+    /// The proportion of the streams recorded with a total number of barters and fights, or 0 if there are none.
+    pub fn frequency(&self, key: (u32, u32)) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.count(key) as f64 / self.total as f64
+        }
+    }
+
+    /// The buckets that have been recorded, sorted by barters and then by fights.
+    pub fn buckets(&self) -> Vec<JointBucket> {
+        self.counts
+            .iter()
+            .map(|(&(barters, fights), &count)| JointBucket {
+                barters,
+                fights,
+                count,
+                frequency: None,
+                estimated_probability: None,
+            })
+            .collect()
+    }
+}
+
+impl From<&[StreamResults]> for JointHistogram {
+    fn from(results: &[StreamResults]) -> Self {
+        JointHistogram::from_results(results)
+    }
+}
+
+impl From<JointHistogram> for Vec<JointBucket> {
+    fn from(histogram: JointHistogram) -> Self {
+        histogram.buckets()
+    }
+}
+
+impl From<Vec<JointBucket>> for JointHistogram {
+    fn from(buckets: Vec<JointBucket>) -> Self {
+        let mut histogram = JointHistogram::new();
+
+        for bucket in buckets {
+            *histogram
+                .counts
+                .entry((bucket.barters, bucket.fights))
+                .or_insert(0) += bucket.count;
+            histogram.total += bucket.count;
+        }
+
+        histogram
+    }
+}
+
+/// The buckets of a joint histogram, with the frequency and estimated probability of each bucket filled in.
+/// The estimated probability is that of a stream with the bucket's totals, taking its successful barters to be the
+/// mean number needed to reach the target, like [quantile](EnderPearlDistribution::quantile) does, since the
+/// histogram doesn't keep the successful barters of the streams it records.
+/// The buckets are sorted by barters, then by fights.
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::run::RunGoals;
+/// # use mc_sim::stats::{self, JointHistogram};
+/// # use mc_sim::stream::StreamResults;
+/// let goals = vec![RunGoals::new(10, 7); 2];
+/// let (barter_list, blaze_list) = (drop_list::barter_drop_list(20, 10), drop_list::blaze_drop_list(14));
+/// let successful_barters = barter_list.distribution().unwrap().distribution().r().round() as u32;
+/// let results = vec![
+///     StreamResults::new(&goals, 40, 30, successful_barters, 14),
+///     StreamResults::new(&goals, 25, 20, 4, 14),
+///     StreamResults::new(&goals, 40, 30, 4, 14),
+///     StreamResults::new(&goals, 40, 28, 5, 14),
+/// ];
+///
+/// let buckets = stats::joint_distribution(&JointHistogram::from_results(&results), &barter_list, &blaze_list);
+/// assert_eq!(buckets.len(), 3);
+/// assert_eq!((buckets[0].barters, buckets[0].fights, buckets[0].count), (25, 20, 1));
+/// assert_eq!((buckets[2].barters, buckets[2].fights, buckets[2].count), (40, 30, 2));
+/// assert_eq!(buckets[2].frequency, Some(0.5));
+/// assert_eq!(buckets[2].estimated_probability, Some(results[0].probability(&barter_list, &blaze_list)));
+/// ```
+pub fn joint_distribution(
+    histogram: &JointHistogram,
+    barter_drop_list: &DropList<EnderPearlDistribution>,
+    blaze_drop_list: &DropList<BlazeRodDistribution>,
+) -> Vec<JointBucket> {
+    histogram
+        .buckets()
+        .into_iter()
+        .map(|bucket| {
+            let pearl_probability = barter_drop_list.distribution().map_or(1.0, |d| {
+                d.probability(bucket.barters, d.distribution().r().round() as u32)
+            });
+            let rod_probability = blaze_drop_list
+                .distribution()
+                .map_or(1.0, |d| d.probability(bucket.fights));
+
+            JointBucket {
+                frequency: Some(histogram.frequency((bucket.barters, bucket.fights))),
+                estimated_probability: Some(pearl_probability * rod_probability),
+                ..bucket
+            }
+        })
+        .collect()
+}
+
 /// Answers the question "how many dice do I need to roll to get to a target"?
 /// Implementation based on the answer by Varun Vejalla: [https://math.stackexchange.com/a/3965269/867664](https://math.stackexchange.com/a/3965269/867664)
 /// ```
@@ -1,11 +1,17 @@
-use crate::drop::DropSim;
+use crate::drop::{DropConfig, DropSim, Item};
 use crate::drop_list::DropList;
-use crate::run::{Run, RunGoals, RunSim};
-use crate::stats::{BlazeRodDistribution, EnderPearlDistribution};
+use crate::error::McSimError;
+use crate::run::{Run, RunGoals, RunSim, GOLD_PER_BARTER};
+use crate::stats::{self, BlazeRodDistribution, CachedDistribution, EnderPearlDistribution};
+use statrs::distribution::{Binomial, Univariate};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read};
+use std::path::Path;
 
 /// A summary of the results of a stream, targeted around answering questions about
 /// how lucky we got with piglins barters and blaze fights specifically.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct StreamResults {
     pub number_of_runs: u32,
     pub total_barters: u32,
@@ -15,11 +21,35 @@ pub struct StreamResults {
     total_target_pearls: u32,
     average_target_pearls_per_run: u32,
     total_target_rods: u32,
+    /// The number of pearls obtained beyond the target of each run, summed over the stream.
+    #[serde(default)]
+    pub pearl_overshoot: u32,
+    /// The number of blaze rods obtained beyond the target of each run, summed over the stream.
+    #[serde(default)]
+    pub rod_overshoot: u32,
+    /// The total count of each item dropped across all barters and fights in the stream.
+    /// These are only known for results that were made from a whole [Stream], which includes every stream simulated
+    /// by a simulation's worker threads while the feature is enabled. See: [item_totals](Stream::item_totals)
+    /// ```
+    /// #[cfg(all(feature = "threaded", feature = "item_totals"))]
+    /// {
+    ///     # use mc_sim::sim::*;
+    ///     let goals = SimulationGoalsBuilder::new().add_runs(2, 10, 7).goals();
+    ///     let results = Simulation::new(goals, 1).simulate_n_times(10);
+    ///     assert!(results.iter().all(|r| r.item_totals.is_some()));
+    /// }
+    /// ```
+    #[cfg(feature = "item_totals")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_totals: Option<HashMap<Item, u32>>,
 }
 
 impl StreamResults {
     /// Creates stream results from the goals of all of the runs in the stream,
     /// and the total number of barters and fights that stream had to get to those goals.
+    ///
+    /// # Panics
+    /// If the total targets of the goals don't fit in a `u32`. See: [try_new](StreamResults::try_new)
     pub fn new(
         goals: &[RunGoals],
         total_barters: u32,
@@ -27,22 +57,175 @@ impl StreamResults {
         successful_barters: u32,
         successful_fights: u32,
     ) -> Self {
-        let total_target_pearls = goals.iter().map(|r| r.target_pearls).sum();
-        let total_target_rods = goals.iter().map(|r| r.target_rods).sum();
-        let average_target_pearls_per_run = total_target_pearls / goals.len() as u32;
+        StreamResults::try_new(
+            goals,
+            total_barters,
+            total_fights,
+            successful_barters,
+            successful_fights,
+        )
+        .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates stream results like [new](StreamResults::new),
+    /// but gives an error rather than panicking if the total targets of the goals don't fit in a `u32`.
+    /// ```
+    /// # use mc_sim::error::McSimError;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let goals = vec![RunGoals::new(10, 7); 22];
+    /// assert_eq!(StreamResults::try_new(&goals, 262, 305, 42, 211).unwrap(), StreamResults::new(&goals, 262, 305, 42, 211));
+    ///
+    /// let goals = vec![RunGoals::new(u32::MAX / 2, 7); 3];
+    /// let error = StreamResults::try_new(&goals, 262, 305, 42, 211).unwrap_err();
+    /// assert!(matches!(error, McSimError::TotalOverflow("target pearls")));
+    /// ```
+    pub fn try_new(
+        goals: &[RunGoals],
+        total_barters: u32,
+        total_fights: u32,
+        successful_barters: u32,
+        successful_fights: u32,
+    ) -> Result<Self, McSimError> {
+        let number_of_runs = stats::checked_total(goals.iter().map(|_| 1), "runs")?;
+        let total_target_pearls =
+            stats::checked_total(goals.iter().map(|r| r.target_pearls), "target pearls")?;
+        let total_target_rods =
+            stats::checked_total(goals.iter().map(|r| r.target_rods), "target rods")?;
+        let average_target_pearls_per_run = total_target_pearls / number_of_runs;
 
+        Ok(StreamResults::from_observed(
+            number_of_runs,
+            total_barters,
+            total_fights,
+            successful_barters,
+            successful_fights,
+            total_target_pearls,
+            average_target_pearls_per_run,
+            total_target_rods,
+        ))
+    }
+
+    /// Creates stream results directly from the totals observed over a stream, such as those counted from VoDs.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::stream::*;
+    /// // Dream's 17 runs that got 10+ pearls took (at worst) 239 barters, of which 39 dropped pearls.
+    /// let pearl_runs = StreamResults::from_observed(17, 239, 0, 39, 0, 170, 10, 0);
+    /// let barter_drop_list = drop_list::barter_drop_list(170, 10);
+    /// assert_eq!(pearl_runs.pearl_luck(&barter_drop_list), 0.0000000006713608557973316);
+    ///
+    /// // Dream's 22 runs took 305 blaze fights to get 211 rods.
+    /// let rod_runs = StreamResults::from_observed(22, 0, 305, 0, 211, 0, 0, 211);
+    /// let blaze_drop_list = drop_list::blaze_drop_list(211);
+    /// assert_eq!(rod_runs.rod_luck(&blaze_drop_list), 0.000000000008791412042796765);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_observed(
+        number_of_runs: u32,
+        total_barters: u32,
+        total_fights: u32,
+        successful_barters: u32,
+        successful_fights: u32,
+        total_target_pearls: u32,
+        average_target_pearls_per_run: u32,
+        total_target_rods: u32,
+    ) -> Self {
         Self {
+            number_of_runs,
             total_barters,
             total_fights,
             successful_barters,
             successful_fights,
-            number_of_runs: goals.len() as u32,
             total_target_pearls,
             average_target_pearls_per_run,
             total_target_rods,
+            pearl_overshoot: 0,
+            rod_overshoot: 0,
+            #[cfg(feature = "item_totals")]
+            item_totals: None,
         }
     }
 
+    /// Sets the number of pearls and blaze rods obtained beyond the targets of the runs in the stream.
+    /// Results created from observed totals don't know these, so they are zero until set.
+    pub fn with_overshoot(mut self, pearl_overshoot: u32, rod_overshoot: u32) -> Self {
+        self.pearl_overshoot = pearl_overshoot;
+        self.rod_overshoot = rod_overshoot;
+        self
+    }
+
+    /// The total number of ender pearls targeted across all runs in the stream.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// # use mc_sim::stream::*;
+    /// let goals = SimulationGoalsBuilder::new()
+    ///     .add_run(10, 7)
+    ///     .add_run(12, 6)
+    ///     .goals();
+    /// let results = StreamResults::new(&goals.streams[0], 50, 30, 5, 13);
+    /// assert_eq!(results.total_target_pearls(), 22);
+    /// assert_eq!(results.average_target_pearls_per_run(), 11);
+    /// assert_eq!(results.total_target_rods(), 13);
+    /// ```
+    pub fn total_target_pearls(&self) -> u32 {
+        self.total_target_pearls
+    }
+
+    /// The average number of ender pearls targeted per run in the stream.
+    pub fn average_target_pearls_per_run(&self) -> u32 {
+        self.average_target_pearls_per_run
+    }
+
+    /// The total number of blaze rods targeted across all runs in the stream.
+    pub fn total_target_rods(&self) -> u32 {
+        self.total_target_rods
+    }
+
+    /// The number of gold ingots spent on barters across the stream. See: [GOLD_PER_BARTER]
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// # use mc_sim::stream::*;
+    /// let goals = SimulationGoals::dream_scenario();
+    /// let results = StreamResults::new(&goals.streams[0], 937, 308, 44, 154);
+    /// assert_eq!(results.gold_spent(), 937);
+    /// ```
+    pub fn gold_spent(&self) -> u32 {
+        self.total_barters * GOLD_PER_BARTER
+    }
+
+    /// The number of barters made per ender pearl targeted, or `None` if no pearls were targeted.
+    /// ```
+    /// # use mc_sim::sim::*;
+    /// # use mc_sim::stream::*;
+    /// let (runs, pearls, rods) = (22, 10, 7);
+    /// let goals = SimulationGoalsBuilder::new().add_runs(runs, pearls, rods).goals();
+    /// let results = StreamResults::new(&goals.streams[0], 937, 308, 44, 154);
+    /// assert_eq!(results.barters_per_pearl(), Some(937.0 / 220.0));
+    /// assert_eq!(results.blazes_per_rod(), Some(2.0));
+    ///
+    /// let goals = SimulationGoalsBuilder::new().add_runs(runs, 0, rods).goals();
+    /// let results = StreamResults::new(&goals.streams[0], 0, 308, 0, 154);
+    /// assert_eq!(results.barters_per_pearl(), None);
+    /// ```
+    pub fn barters_per_pearl(&self) -> Option<f64> {
+        if self.total_target_pearls == 0 {
+            return None;
+        }
+
+        Some(self.total_barters as f64 / self.total_target_pearls as f64)
+    }
+
+    /// The number of blazes fought per blaze rod targeted, or `None` if no rods were targeted.
+    /// See: [barters_per_pearl](StreamResults::barters_per_pearl)
+    pub fn blazes_per_rod(&self) -> Option<f64> {
+        if self.total_target_rods == 0 {
+            return None;
+        }
+
+        Some(self.total_fights as f64 / self.total_target_rods as f64)
+    }
+
     /// Estimates a p-value for the stream results being this lucky.
     /// Lucky meaning fewest barters and blaze fights, accounting for how
     /// likely each of those are.
@@ -51,16 +234,38 @@ impl StreamResults {
     /// ```
     /// # use mc_sim::drop_list;
     /// # use mc_sim::sim::*;
+    /// # use mc_sim::stats;
     /// # use mc_sim::stream::*;
     /// let (runs, pearls, rods) = (22, 10, 7);
     /// let goals = SimulationGoalsBuilder::new().add_runs(runs, pearls, rods).goals();
     /// let (target_pearls, target_rods) = (runs * pearls, runs * rods);
     /// let (total_barters, total_fights) = (937, 308);
-    /// let (successful_barters, successful_fights) = ((target_pearls * 20 * 25) / (53 * 423), target_rods);
+    /// let barter_drop_list = drop_list::barter_drop_list(target_pearls, pearls);
+    /// let (successful_barters, successful_fights) =
+    ///     (stats::expected_successful_barters(target_pearls, barter_drop_list.list()), target_rods);
     /// let results = StreamResults::new(&goals.streams[0], total_barters, total_fights, successful_barters, successful_fights);
-    /// assert_eq!(results.pearl_luck(&drop_list::barter_drop_list(target_pearls, pearls)), 0.5016436716111609);
+    /// assert_eq!(results.pearl_luck(&barter_drop_list), 0.4076498665653079);
     /// assert_eq!(results.rod_luck(&drop_list::blaze_drop_list(target_rods)), 0.5227134024692426);
-    /// assert_eq!(results.luck(&drop_list::barter_drop_list(target_pearls, pearls), &drop_list::blaze_drop_list(target_rods)), 0.2622158704150333);
+    /// assert_eq!(results.luck(&barter_drop_list, &drop_list::blaze_drop_list(target_rods)), 0.2130840487684848);
+    /// ```
+    ///
+    /// A stream with more barters than another can still be luckier, when it has far fewer fights.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::sim::*;
+    /// # use mc_sim::stream::*;
+    /// let goals = SimulationGoals::dream_scenario();
+    /// let (barter_list, blaze_list) = (drop_list::barter_drop_list(220, 10), drop_list::blaze_drop_list(154));
+    /// let fewer_barters = StreamResults::new(&goals.streams[0], 900, 320, 44, 154);
+    /// let fewer_fights = StreamResults::new(&goals.streams[0], 901, 250, 44, 154);
+    /// assert!(fewer_fights.total_barters > fewer_barters.total_barters);
+    /// assert!(fewer_fights.luck(&barter_list, &blaze_list) < fewer_barters.luck(&barter_list, &blaze_list));
+    ///
+    /// // Even with more barters and no fewer fights, more of the barters being successful can make it luckier.
+    /// let more_successful = StreamResults::new(&goals.streams[0], 901, 320, 50, 154);
+    /// assert!(more_successful.total_barters > fewer_barters.total_barters);
+    /// assert!(more_successful.total_fights >= fewer_barters.total_fights);
+    /// assert!(more_successful.luck(&barter_list, &blaze_list) < fewer_barters.luck(&barter_list, &blaze_list));
     /// ```
     pub fn luck(
         &self,
@@ -70,21 +275,85 @@ impl StreamResults {
         self.pearl_luck(barter_drop_list) * self.rod_luck(blaze_drop_list)
     }
 
+    /// Estimates a p-value for the stream results like [luck](StreamResults::luck), but using cached distributions.
+    /// This is much faster when computing the luck of many stream results that share the same totals.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::sim::*;
+    /// # use mc_sim::stats::CachedDistribution;
+    /// # use mc_sim::stream::*;
+    /// let goals = SimulationGoals::dream_scenario();
+    /// let (barter_list, blaze_list) = (drop_list::barter_drop_list(220, 10), drop_list::blaze_drop_list(154));
+    /// let pearls = CachedDistribution::new(barter_list.distribution().unwrap());
+    /// let rods = CachedDistribution::new(blaze_list.distribution().unwrap());
+    ///
+    /// for (barters, fights) in [(937, 308), (900, 320), (937, 308)] {
+    ///     let results = StreamResults::new(&goals.streams[0], barters, fights, 44, 154);
+    ///     assert_eq!(results.cached_luck(&pearls, &rods), results.luck(&barter_list, &blaze_list));
+    /// }
+    /// ```
+    pub fn cached_luck(
+        &self,
+        pearl_distribution: &CachedDistribution<EnderPearlDistribution>,
+        rod_distribution: &CachedDistribution<BlazeRodDistribution>,
+    ) -> f64 {
+        let pearl_luck = if self.total_target_pearls == 0 {
+            1.0
+        } else {
+            pearl_distribution.luck(self.total_barters, self.successful_barters)
+        };
+
+        let rod_luck = if self.total_target_rods == 0 {
+            1.0
+        } else {
+            rod_distribution.luck(self.total_fights)
+        };
+
+        pearl_luck * rod_luck
+    }
+
+    /// Estimates a p-value for the stream results, like [luck](StreamResults::luck),
+    /// but with the pearl and rod luck raised to the power of their weights.
+    /// A weight of zero ignores that dimension entirely, and weights of one give the same result as luck.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::sim::*;
+    /// # use mc_sim::stream::*;
+    /// let goals = SimulationGoals::dream_scenario();
+    /// let results = StreamResults::new(&goals.streams[0], 937, 308, 10, 154);
+    /// let (barter_list, blaze_list) = (drop_list::barter_drop_list(220, 10), drop_list::blaze_drop_list(154));
+    /// assert_eq!(results.weighted_luck(&barter_list, &blaze_list, 1.0, 0.0), results.pearl_luck(&barter_list));
+    /// assert_eq!(results.weighted_luck(&barter_list, &blaze_list, 1.0, 1.0), results.luck(&barter_list, &blaze_list));
+    /// ```
+    pub fn weighted_luck(
+        &self,
+        barter_drop_list: &DropList<EnderPearlDistribution>,
+        blaze_drop_list: &DropList<BlazeRodDistribution>,
+        pearl_weight: f64,
+        rod_weight: f64,
+    ) -> f64 {
+        self.pearl_luck(barter_drop_list).powf(pearl_weight)
+            * self.rod_luck(blaze_drop_list).powf(rod_weight)
+    }
+
     /// Estimates a p-value for the stream results exact number of barters and fights.
     /// Probability meaning how likely this outcome was, not how lucky it was. See: [luck](StreamResults::luck)
     /// ```
     /// # use mc_sim::drop_list;
     /// # use mc_sim::sim::*;
+    /// # use mc_sim::stats;
     /// # use mc_sim::stream::*;
     /// let (runs, pearls, rods) = (22, 10, 7);
     /// let goals = SimulationGoalsBuilder::new().add_runs(runs, pearls, rods).goals();
     /// let (target_pearls, target_rods) = (runs * pearls, runs * rods);
     /// let (total_barters, total_fights) = (937, 308);
-    /// let (successful_barters, successful_fights) = ((target_pearls * 20 * 25) / (53 * 423), target_rods);
+    /// let barter_drop_list = drop_list::barter_drop_list(target_pearls, pearls);
+    /// let (successful_barters, successful_fights) =
+    ///     (stats::expected_successful_barters(target_pearls, barter_drop_list.list()), target_rods);
     /// let results = StreamResults::new(&goals.streams[0], total_barters, total_fights, successful_barters, successful_fights);
-    /// assert_eq!(results.pearl_probability(&drop_list::barter_drop_list(target_pearls, pearls)), 0.0028413877468180587);
+    /// assert_eq!(results.pearl_probability(&barter_drop_list), 0.002828158980899408);
     /// assert_eq!(results.rod_probability(&drop_list::blaze_drop_list(target_rods)), 0.022713402469194337);
-    /// assert_eq!(results.probability(&drop_list::barter_drop_list(target_pearls, pearls), &drop_list::blaze_drop_list(target_rods)), 0.00006453758346451583);
+    /// assert_eq!(results.probability(&barter_drop_list, &drop_list::blaze_drop_list(target_rods)), 0.00006423711318003475);
     /// ```
     pub fn probability(
         &self,
@@ -95,6 +364,26 @@ impl StreamResults {
     }
 
     /// Estimates the stream results luck specifically for ender pearls. See: [luck](StreamResults::luck)
+    ///
+    /// Streams that target no ender pearls have nothing to be lucky about, so their pearl luck is 1.0.
+    /// This leaves the combined luck of a rods-only stream equal to its rod luck.
+    /// ```
+    /// # use mc_sim::drop::DropSim;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let barter_drop_list = drop_list::barter_drop_list(0, 0);
+    /// let blaze_drop_list = drop_list::blaze_drop_list(14);
+    /// let mut barter_drop_sim = DropSim::new(barter_drop_list.list_clone());
+    /// let mut blaze_drop_sim = DropSim::new(blaze_drop_list.list_clone());
+    ///
+    /// let goals = vec![RunGoals::rods_only(7); 2];
+    /// let results = Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, goals).results();
+    ///
+    /// assert_eq!(results.total_barters, 0);
+    /// assert_eq!(results.pearl_luck(&barter_drop_list), 1.0);
+    /// assert_eq!(results.luck(&barter_drop_list, &blaze_drop_list), results.rod_luck(&blaze_drop_list));
+    /// ```
     pub fn pearl_luck(&self, barter_drop_list: &DropList<EnderPearlDistribution>) -> f64 {
         if self.total_target_pearls == 0 {
             return 1.0;
@@ -107,6 +396,26 @@ impl StreamResults {
     }
 
     /// Estimates the stream results luck specifically for blaze rods. See: [luck](StreamResults::luck)
+    ///
+    /// Streams that target no blaze rods have nothing to be lucky about, so their rod luck is 1.0.
+    /// This leaves the combined luck of a pearls-only stream equal to its pearl luck.
+    /// ```
+    /// # use mc_sim::drop::DropSim;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let barter_drop_list = drop_list::barter_drop_list(20, 10);
+    /// let blaze_drop_list = drop_list::blaze_drop_list(0);
+    /// let mut barter_drop_sim = DropSim::new(barter_drop_list.list_clone());
+    /// let mut blaze_drop_sim = DropSim::new(blaze_drop_list.list_clone());
+    ///
+    /// let goals = vec![RunGoals::pearls_only(10); 2];
+    /// let results = Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, goals).results();
+    ///
+    /// assert_eq!(results.total_fights, 0);
+    /// assert_eq!(results.rod_luck(&blaze_drop_list), 1.0);
+    /// assert_eq!(results.luck(&barter_drop_list, &blaze_drop_list), results.pearl_luck(&barter_drop_list));
+    /// ```
     pub fn rod_luck(&self, blaze_drop_list: &DropList<BlazeRodDistribution>) -> f64 {
         if self.total_target_rods == 0 {
             return 1.0;
@@ -119,9 +428,26 @@ impl StreamResults {
     }
 
     /// Estimates the stream results probability specifically for ender pearls. See: [probability](StreamResults::probability)
+    ///
+    /// Streams that target no ender pearls certainly make no barters, so their pearl probability is 1.0.
+    /// This leaves the combined probability of a rods-only stream equal to its rod probability.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let barter_drop_list = drop_list::barter_drop_list(0, 0);
+    /// let blaze_drop_list = drop_list::blaze_drop_list(14);
+    /// let results = StreamResults::new(&[RunGoals::rods_only(7); 2], 0, 30, 0, 14);
+    ///
+    /// assert_eq!(results.pearl_probability(&barter_drop_list), 1.0);
+    /// assert_eq!(
+    ///     results.probability(&barter_drop_list, &blaze_drop_list),
+    ///     results.rod_probability(&blaze_drop_list)
+    /// );
+    /// ```
     pub fn pearl_probability(&self, barter_drop_list: &DropList<EnderPearlDistribution>) -> f64 {
         if self.total_target_pearls == 0 {
-            return 0.0;
+            return 1.0;
         }
 
         barter_drop_list
@@ -130,10 +456,12 @@ impl StreamResults {
             .probability(self.total_barters, self.successful_barters)
     }
 
-    /// Estimates the stream results luck specifically for blaze rods. See: [probability](StreamResults::probability)
+    /// Estimates the stream results probability specifically for blaze rods. See: [probability](StreamResults::probability)
+    ///
+    /// Streams that target no blaze rods certainly fight no blazes, so their rod probability is 1.0.
     pub fn rod_probability(&self, blaze_drop_list: &DropList<BlazeRodDistribution>) -> f64 {
         if self.total_target_rods == 0 {
-            return 0.0;
+            return 1.0;
         }
 
         blaze_drop_list
@@ -141,17 +469,220 @@ impl StreamResults {
             .unwrap()
             .probability(self.total_fights)
     }
+
+    /// The proportion of barters in the stream that dropped ender pearls, or NaN if no barters were made.
+    /// ```
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let results = StreamResults::new(&[RunGoals::new(10, 7); 2], 40, 30, 4, 15);
+    /// assert_eq!(results.barter_success_rate(), 0.1);
+    /// assert_eq!(results.fight_success_rate(), 0.5);
+    /// ```
+    pub fn barter_success_rate(&self) -> f64 {
+        self.successful_barters as f64 / self.total_barters as f64
+    }
+
+    /// The proportion of blaze fights in the stream that dropped blaze rods, or NaN if no blazes were fought.
+    pub fn fight_success_rate(&self) -> f64 {
+        self.successful_fights as f64 / self.total_fights as f64
+    }
+
+    /// Estimates a p-value for the stream results being this lucky, like [luck](StreamResults::luck), but by the
+    /// rate of successful barters and fights rather than how many it took to reach the targets.
+    /// Each rate is tested against the probability of a single barter or fight being successful, as the probability
+    /// of at least as many successes from the same number of attempts. The pearl and rod p-values are multiplied,
+    /// and either is 1.0 if its item wasn't targeted. Fails if a targeted item isn't on its drop list.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let barter_drop_list = drop_list::barter_drop_list(0, 0);
+    /// let blaze_drop_list = drop_list::blaze_drop_list(10);
+    ///
+    /// // Half of the fights drop a rod, so at least 10 of 20 is (2^20 + (20 choose 10)) / 2^21.
+    /// let results = StreamResults::new(&[RunGoals::rods_only(10)], 0, 20, 0, 10);
+    /// let expected = (1048576.0 + 184756.0) / 2097152.0;
+    /// assert!((results.success_rate_luck(&barter_drop_list, &blaze_drop_list).unwrap() - expected).abs() < 1e-9);
+    ///
+    /// // Every one of 5 barters dropping pearls is very lucky.
+    /// let barter_drop_list = drop_list::barter_drop_list(20, 10);
+    /// let results = StreamResults::new(&[RunGoals::new(20, 10)], 5, 20, 5, 10);
+    /// let expected = expected * (20.0f64 / 423.0).powi(5);
+    /// assert!((results.success_rate_luck(&barter_drop_list, &blaze_drop_list).unwrap() / expected - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn success_rate_luck(
+        &self,
+        barter_drop_list: &DropList<EnderPearlDistribution>,
+        blaze_drop_list: &DropList<BlazeRodDistribution>,
+    ) -> Result<f64, McSimError> {
+        let pearl_luck = if self.total_target_pearls == 0 {
+            1.0
+        } else {
+            at_least_as_successful(
+                self.successful_barters,
+                self.total_barters,
+                stats::item_success_probability(barter_drop_list.list(), Item::EnderPearl)?,
+            )?
+        };
+
+        let rod_luck = if self.total_target_rods == 0 {
+            1.0
+        } else {
+            at_least_as_successful(
+                self.successful_fights,
+                self.total_fights,
+                stats::item_success_probability(blaze_drop_list.list(), Item::BlazeRod)?,
+            )?
+        };
+
+        Ok(pearl_luck * rod_luck)
+    }
+}
+
+/// The probability of at least a number of successes from a number of attempts, each with the same probability.
+fn at_least_as_successful(
+    successes: u32,
+    attempts: u32,
+    probability: f64,
+) -> Result<f64, McSimError> {
+    if successes == 0 {
+        return Ok(1.0);
+    }
+
+    let distribution = Binomial::new(probability, attempts as u64).map_err(|err| {
+        McSimError::InvalidDistribution {
+            reason: format!("expected 0 <= p <= 1, got p = {}", probability),
+            err,
+        }
+    })?;
+    Ok((1.0 - distribution.cdf(successes as f64 - 1.0)).max(0.0))
+}
+
+impl std::fmt::Display for StreamResults {
+    /// A human readable summary of the stream results.
+    /// ```
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let goals = vec![RunGoals::new(10, 7); 22];
+    /// let results = StreamResults::new(&goals, 262, 305, 42, 211);
+    /// assert_eq!(
+    ///     results.to_string(),
+    ///     "22 runs: 262 barters (42 successful) for 220 pearls, 305 fights (211 successful) for 154 rods"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} runs: {} barters ({} successful) for {} pearls, {} fights ({} successful) for {} rods",
+            self.number_of_runs,
+            self.total_barters,
+            self.successful_barters,
+            self.total_target_pearls,
+            self.total_fights,
+            self.successful_fights,
+            self.total_target_rods,
+        )
+    }
+}
+
+impl StreamResults {
+    /// A human readable summary of the stream results, like [Display](std::fmt::Display), along with their luck.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let goals = vec![RunGoals::new(10, 7); 22];
+    /// let results = StreamResults::new(&goals, 262, 305, 42, 211);
+    /// let (barter_list, blaze_list) = (drop_list::barter_drop_list(220, 10), drop_list::blaze_drop_list(154));
+    ///
+    /// let formatted = results.format_with(&barter_list, &blaze_list);
+    /// assert!(formatted.starts_with(&results.to_string()));
+    /// assert!(formatted.contains(&format!("luck: {}", results.luck(&barter_list, &blaze_list))));
+    /// assert!(formatted.contains(&format!("pearl luck: {}", results.pearl_luck(&barter_list))));
+    /// assert!(formatted.contains(&format!("rod luck: {}", results.rod_luck(&blaze_list))));
+    /// ```
+    pub fn format_with(
+        &self,
+        barter_drop_list: &DropList<EnderPearlDistribution>,
+        blaze_drop_list: &DropList<BlazeRodDistribution>,
+    ) -> String {
+        format!(
+            "{}, luck: {} (pearl luck: {}, rod luck: {})",
+            self,
+            self.luck(barter_drop_list, blaze_drop_list),
+            self.pearl_luck(barter_drop_list),
+            self.rod_luck(blaze_drop_list),
+        )
+    }
 }
 
 /// A single 'stream' of minecraft speed runs.
 /// I.E. A list of speed runs.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct Stream {
     pub runs: Vec<Run>,
     pub goals: Vec<RunGoals>,
+    /// The seed the stream was simulated from, if it was seeded. See: [replay](Stream::replay)
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// The index of the stream in the sequence of streams simulated by a simulation worker thread, if known.
+    #[serde(default)]
+    pub sequence_index: Option<u64>,
 }
 
+/// Mixed into a stream's seed to get the seed for blaze fights, so that they don't share barter RNG.
+const BLAZE_SEED_SALT: u64 = 0x9e37_79b9_7f4a_7c15;
+
 impl Stream {
+    /// Creates a stream from runs that have already happened, such as those recorded from real play,
+    /// and the goals of each of those runs. See: [new_checked](Stream::new_checked)
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::run::*;
+    /// # use mc_sim::stream::*;
+    /// let first = Run::new(
+    ///     vec![
+    ///         Drop { item: Item::Gravel, roll: 0, count: 8 },
+    ///         Drop { item: Item::EnderPearl, roll: 0, count: 6 },
+    ///         Drop { item: Item::EnderPearl, roll: 0, count: 4 },
+    ///     ],
+    ///     vec![Drop { item: Item::BlazeRod, roll: 0, count: 1 }],
+    /// );
+    /// let second = Run::new(
+    ///     vec![Drop { item: Item::EnderPearl, roll: 0, count: 8 }],
+    ///     vec![Drop { item: Item::BlazeRod, roll: 0, count: 1 }],
+    /// );
+    ///
+    /// let stream = Stream::from_runs(vec![first, second], vec![RunGoals::new(8, 1); 2]);
+    /// assert_eq!(stream.total_pearls(), 18);
+    /// assert_eq!(stream.results().total_barters, 4);
+    /// ```
+    pub fn from_runs(runs: Vec<Run>, goals: Vec<RunGoals>) -> Self {
+        Self {
+            runs,
+            goals,
+            seed: None,
+            sequence_index: None,
+        }
+    }
+
+    /// Creates a stream from runs like [from_runs](Stream::from_runs), but with an error if there isn't
+    /// exactly one set of goals for each run.
+    /// ```
+    /// # use mc_sim::run::*;
+    /// # use mc_sim::stream::*;
+    /// let runs = vec![Run::new(vec![], vec![]), Run::new(vec![], vec![])];
+    /// assert!(Stream::new_checked(runs.clone(), vec![RunGoals::pearls_only(0); 2]).is_ok());
+    /// assert!(Stream::new_checked(runs, vec![RunGoals::pearls_only(0); 3]).is_err());
+    /// ```
+    pub fn new_checked(runs: Vec<Run>, goals: Vec<RunGoals>) -> Result<Self, McSimError> {
+        if runs.len() != goals.len() {
+            return Err(McSimError::MismatchedGoals(runs.len(), goals.len()));
+        }
+
+        Ok(Stream::from_runs(runs, goals))
+    }
+
     /// Simulate the stream from drop lists for bartering and blazes, and a list of goals per run.
     /// ```
     /// # use mc_sim::drop::*;
@@ -161,10 +692,10 @@ impl Stream {
     /// let mut barter_drop_sim = DropSim::new(drop_list::barter_drop_list(10, 10).list_clone());
     /// let mut blaze_drop_sim = DropSim::new(drop_list::blaze_drop_list(7).list_clone());
     /// let goals = vec![
-    ///     RunGoals { target_pearls: 10, target_rods: 7 },
-    ///     RunGoals { target_pearls: 10, target_rods: 6 },
-    ///     RunGoals { target_pearls: 10, target_rods: 8 },
-    ///     RunGoals { target_pearls: 10, target_rods: 7 },
+    ///     RunGoals::new(10, 7),
+    ///     RunGoals::new(10, 6),
+    ///     RunGoals::new(10, 8),
+    ///     RunGoals::new(10, 7),
     /// ];
     ///
     /// let stream = Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, goals);
@@ -183,46 +714,327 @@ impl Stream {
             .map(|run| Stream::simulate_run(barter_drop_sim, blaze_drop_sim, &goals, run))
             .collect();
 
-        Self { goals, runs }
+        Self {
+            goals,
+            runs,
+            seed: None,
+            sequence_index: None,
+        }
+    }
+
+    /// Simulate the stream from a seed, so that it can be reproduced exactly later on with [replay](Stream::replay).
+    pub fn simulate_seeded(
+        barter_drop_list: &[DropConfig],
+        blaze_drop_list: &[DropConfig],
+        goals: Vec<RunGoals>,
+        seed: u64,
+    ) -> Self {
+        let (mut barter_drop_sim, mut blaze_drop_sim) =
+            Stream::seeded_drop_sims(barter_drop_list, blaze_drop_list, seed);
+
+        Self {
+            seed: Some(seed),
+            ..Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, goals)
+        }
+    }
+
+    /// Simulate the stream like [simulate](Stream::simulate), but only keep the results rather than all of the drops.
+    /// This avoids allocating for every drop, which makes it much faster when the drops themselves aren't needed.
+    /// The item totals aren't known from the results alone, so they are never included.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// # use mc_sim::stream::*;
+    /// let barter_drop_list = drop_list::barter_drop_list(40, 10).list_clone();
+    /// let blaze_drop_list = drop_list::blaze_drop_list(28).list_clone();
+    /// let goals = vec![RunGoals::new(10, 7); 4];
+    ///
+    /// let mut barter_drop_sim = DropSim::from_seed(barter_drop_list.clone(), 1);
+    /// let mut blaze_drop_sim = DropSim::from_seed(blaze_drop_list.clone(), 2);
+    /// let results = Stream::simulate_results_only(&mut barter_drop_sim, &mut blaze_drop_sim, &goals);
+    ///
+    /// let mut barter_drop_sim = DropSim::from_seed(barter_drop_list, 1);
+    /// let mut blaze_drop_sim = DropSim::from_seed(blaze_drop_list, 2);
+    /// let stream = Stream::simulate(&mut barter_drop_sim, &mut blaze_drop_sim, goals);
+    /// # let mut results = results;
+    /// # #[cfg(feature = "item_totals")]
+    /// # { results.item_totals = stream.results().item_totals; }
+    ///
+    /// assert_eq!(results, stream.results());
+    /// ```
+    pub fn simulate_results_only(
+        barter_drop_sim: &mut DropSim,
+        blaze_drop_sim: &mut DropSim,
+        goals: &[RunGoals],
+    ) -> StreamResults {
+        let (mut total_barters, mut successful_barters, mut pearl_overshoot) = (0, 0, 0);
+        let (mut total_fights, mut successful_fights, mut rod_overshoot) = (0, 0, 0);
+
+        for run_goals in goals {
+            let (barters, successful, pearls) = RunSim::tally_for_goals(barter_drop_sim, run_goals);
+            total_barters += barters;
+            successful_barters += successful;
+            pearl_overshoot += pearls.saturating_sub(run_goals.target_pearls);
+
+            let (fights, successful, rods) =
+                RunSim::tally_for_item(blaze_drop_sim, Item::BlazeRod, run_goals.target_rods);
+            total_fights += fights;
+            successful_fights += successful;
+            rod_overshoot += rods.saturating_sub(run_goals.target_rods);
+        }
+
+        StreamResults::new(
+            goals,
+            total_barters,
+            total_fights,
+            successful_barters,
+            successful_fights,
+        )
+        .with_overshoot(pearl_overshoot, rod_overshoot)
+    }
+
+    /// Creates the drop simulators for bartering and blaze fights used to simulate a stream from a seed.
+    pub(crate) fn seeded_drop_sims(
+        barter_drop_list: &[DropConfig],
+        blaze_drop_list: &[DropConfig],
+        seed: u64,
+    ) -> (DropSim, DropSim) {
+        (
+            DropSim::from_seed(barter_drop_list.to_vec(), seed),
+            DropSim::from_seed(blaze_drop_list.to_vec(), seed ^ BLAZE_SEED_SALT),
+        )
+    }
+
+    /// Reseeds the drop simulators for bartering and blaze fights, so that they are in the same state as
+    /// those from [seeded_drop_sims](Stream::seeded_drop_sims) with the same seed.
+    #[cfg(feature = "threaded")]
+    pub(crate) fn reseed_drop_sims(
+        barter_drop_sim: &mut DropSim,
+        blaze_drop_sim: &mut DropSim,
+        seed: u64,
+    ) {
+        barter_drop_sim.reseed(seed);
+        blaze_drop_sim.reseed(seed ^ BLAZE_SEED_SALT);
+    }
+
+    /// Re-simulates a seeded stream, reproducing exactly the same drops as long as the drop lists are the same ones
+    /// it was simulated with.
+    /// ```
+    /// # use mc_sim::drop::{DropConfig, Item};
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// # use mc_sim::stream::*;
+    /// let barter_drop_list = drop_list::barter_drop_list(40, 10);
+    /// let blaze_drop_list = drop_list::blaze_drop_list(28);
+    /// let goals = vec![RunGoals::new(10, 7); 4];
+    /// let stream = Stream::simulate_seeded(barter_drop_list.list(), blaze_drop_list.list(), goals.clone(), 1234);
+    ///
+    /// let replayed = Stream::replay(barter_drop_list.list(), blaze_drop_list.list(), stream.seed.unwrap(), goals.clone());
+    /// assert_eq!(replayed, stream);
+    ///
+    /// // Streams simulated from custom drop lists are replayed from those same lists.
+    /// let boosted = vec![DropConfig::new(Item::Gravel, 1, 1, 1), DropConfig::new(Item::EnderPearl, 3, 4, 8)];
+    /// let stream = Stream::simulate_seeded(&boosted, blaze_drop_list.list(), goals.clone(), 99);
+    /// assert_eq!(Stream::replay(&boosted, blaze_drop_list.list(), 99, goals), stream);
+    /// ```
+    pub fn replay(
+        barter_drop_list: &[DropConfig],
+        blaze_drop_list: &[DropConfig],
+        seed: u64,
+        goals: Vec<RunGoals>,
+    ) -> Self {
+        Stream::simulate_seeded(barter_drop_list, blaze_drop_list, goals, seed)
+    }
+
+    /// Load a stream, with all of its drops, from a JSON file.
+    /// ```
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::RunGoals;
+    /// # use mc_sim::stream::*;
+    /// let stream = Stream::simulate_seeded(
+    ///     drop_list::barter_drop_list(40, 10).list(),
+    ///     drop_list::blaze_drop_list(28).list(),
+    ///     vec![RunGoals::new(10, 7); 4],
+    ///     42,
+    /// );
+    /// let path = std::env::temp_dir().join("mc_sim_stream_doctest.json");
+    /// stream.save_json(&path).unwrap();
+    ///
+    /// let loaded = Stream::load_json(&path).unwrap();
+    /// assert_eq!(loaded.total_pearls(), stream.total_pearls());
+    /// assert_eq!(loaded, stream);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn load_json(path: impl AsRef<Path>) -> Result<Self, McSimError> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Save a stream, with all of its drops, to a JSON file. See: [load_json](Stream::load_json)
+    pub fn save_json(&self, path: impl AsRef<Path>) -> Result<(), McSimError> {
+        let writer = BufWriter::new(File::create(path)?);
+        Ok(serde_json::to_writer(writer, self)?)
     }
 
     /// The total number of barters made across all runs in the stream.
     pub fn total_barters(&self) -> u32 {
-        self.runs.iter().map(|run| run.total_barters()).sum()
+        Stream::total(self.runs.iter().map(|run| run.total_barters()), "barters")
     }
 
     pub fn successful_barters(&self) -> u32 {
-        self.runs.iter().map(|run| run.successful_barters()).sum()
+        Stream::total(
+            self.runs.iter().map(|run| run.successful_barters()),
+            "successful barters",
+        )
     }
 
     /// The total number of pearls picked up across all runs in the stream.
     pub fn total_pearls(&self) -> u32 {
-        self.runs.iter().map(|run| run.total_pearls()).sum()
+        Stream::total(self.runs.iter().map(|run| run.total_pearls()), "pearls")
     }
 
     /// The total number of blazes killed across all runs in the stream.
     pub fn total_fights(&self) -> u32 {
-        self.runs.iter().map(|run| run.total_fights()).sum()
+        Stream::total(self.runs.iter().map(|run| run.total_fights()), "fights")
     }
 
     pub fn successful_fights(&self) -> u32 {
-        self.runs.iter().map(|run| run.successful_fights()).sum()
+        Stream::total(
+            self.runs.iter().map(|run| run.successful_fights()),
+            "successful fights",
+        )
     }
 
     /// The total number of blaze rods picked up across all runs in the stream.
     pub fn total_rods(&self) -> u32 {
-        self.runs.iter().map(|run| run.total_rods()).sum()
+        Stream::total(self.runs.iter().map(|run| run.total_rods()), "rods")
+    }
+
+    /// The number of pearls obtained beyond the target of each run, summed over the stream.
+    pub fn pearl_overshoot(&self) -> u32 {
+        Stream::total(
+            self.runs
+                .iter()
+                .zip(&self.goals)
+                .map(|(run, goals)| run.pearl_overshoot(goals.target_pearls)),
+            "pearl overshoot",
+        )
+    }
+
+    /// The number of blaze rods obtained beyond the target of each run, summed over the stream.
+    pub fn rod_overshoot(&self) -> u32 {
+        Stream::total(
+            self.runs
+                .iter()
+                .zip(&self.goals)
+                .map(|(run, goals)| run.rod_overshoot(goals.target_rods)),
+            "rod overshoot",
+        )
+    }
+
+    /// Sums a total over the runs of the stream, panicking rather than wrapping if it doesn't fit in a `u32`.
+    fn total(values: impl IntoIterator<Item = u32>, total: &'static str) -> u32 {
+        stats::checked_total(values, total).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// The total count of each item dropped across all barters and fights in the stream.
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::run::*;
+    /// # use mc_sim::stream::*;
+    /// let barters = vec![
+    ///     Drop { item: Item::Gravel, roll: 0, count: 8 },
+    ///     Drop { item: Item::EnderPearl, roll: 0, count: 4 },
+    ///     Drop { item: Item::Gravel, roll: 0, count: 12 },
+    /// ];
+    /// let fights = vec![
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 1 },
+    ///     Drop { item: Item::BlazeRod, roll: 0, count: 0 },
+    /// ];
+    /// let stream = Stream {
+    ///     runs: vec![Run::new(barters.clone(), fights.clone()), Run::new(barters, fights)],
+    ///     goals: vec![RunGoals::new(4, 1); 2],
+    ///     seed: None,
+    ///     sequence_index: None,
+    /// };
+    ///
+    /// let totals = stream.item_totals();
+    /// assert_eq!(totals[&Item::Gravel], 40);
+    /// assert_eq!(totals[&Item::EnderPearl], stream.total_pearls());
+    /// assert_eq!(totals[&Item::BlazeRod], stream.total_rods());
+    /// # #[cfg(feature = "item_totals")]
+    /// # assert_eq!(stream.results().item_totals, Some(totals));
+    /// ```
+    pub fn item_totals(&self) -> HashMap<Item, u32> {
+        let mut totals = HashMap::new();
+
+        for drop in self
+            .runs
+            .iter()
+            .flat_map(|run| run.barters.iter().chain(run.fights.iter()))
+        {
+            *totals.entry(drop.item).or_insert(0) += drop.count;
+        }
+
+        totals
     }
 
     /// A summary of the results of the stream.
+    /// With the `item_totals` feature, this includes the total count of each item dropped.
     pub fn results(&self) -> StreamResults {
-        StreamResults::new(
+        let results = StreamResults::new(
             &self.goals,
             self.total_barters(),
             self.total_fights(),
             self.successful_barters(),
             self.successful_fights(),
         )
+        .with_overshoot(self.pearl_overshoot(), self.rod_overshoot());
+
+        #[cfg(feature = "item_totals")]
+        let results = StreamResults {
+            item_totals: Some(self.item_totals()),
+            ..results
+        };
+
+        results
+    }
+
+    /// Estimates the luck of a single run in the stream, as if it were a stream of its own.
+    /// The drop lists should have distributions for the targets of that single run. See: [luck](StreamResults::luck)
+    /// ```
+    /// # use mc_sim::drop::*;
+    /// # use mc_sim::drop_list;
+    /// # use mc_sim::run::*;
+    /// # use mc_sim::stream::*;
+    /// let pearls = |n| Drop { item: Item::EnderPearl, roll: 0, count: n };
+    /// let gravel = Drop { item: Item::Gravel, roll: 0, count: 8 };
+    /// let rod = Drop { item: Item::BlazeRod, roll: 0, count: 1 };
+    ///
+    /// let lucky = Run::new(vec![pearls(6), pearls(4)], vec![rod.clone(); 7]);
+    /// let unlucky = Run::new([vec![gravel; 60], vec![pearls(5), pearls(5)]].concat(), vec![rod; 7]);
+    /// let stream = Stream::from_runs(vec![lucky, unlucky], vec![RunGoals::new(10, 7); 2]);
+    ///
+    /// let (barter_list, blaze_list) = (drop_list::barter_drop_list(10, 10), drop_list::blaze_drop_list(7));
+    /// assert!(stream.run_luck(0, &barter_list, &blaze_list) < stream.run_luck(1, &barter_list, &blaze_list));
+    /// ```
+    pub fn run_luck(
+        &self,
+        run_index: usize,
+        barter_drop_list: &DropList<EnderPearlDistribution>,
+        blaze_drop_list: &DropList<BlazeRodDistribution>,
+    ) -> f64 {
+        let run = &self.runs[run_index];
+
+        StreamResults::new(
+            &self.goals[run_index..=run_index],
+            run.total_barters(),
+            run.total_fights(),
+            run.successful_barters(),
+            run.successful_fights(),
+        )
+        .luck(barter_drop_list, blaze_drop_list)
     }
 
     /// Simulate a single run.
@@ -238,6 +1050,55 @@ impl Stream {
             goals[run].target_pearls,
             goals[run].target_rods,
         )
+        .with_barter_target(goals[run].barter_target())
+        .with_early_stop(goals[run].early_stop())
         .run()
     }
 }
+
+/// A single row of observed stream data, as read by [read_observed_csv].
+#[derive(Debug, Deserialize)]
+struct ObservedRecord {
+    runs: u32,
+    barters: u32,
+    fights: u32,
+    successful_barters: u32,
+    successful_fights: u32,
+    target_pearls: u32,
+    target_rods: u32,
+}
+
+/// Reads observed stream data from a CSV file, with one stream per row.
+/// The CSV must have the headers: `runs,barters,fights,successful_barters,successful_fights,target_pearls,target_rods`
+/// where the targets are the totals across all runs in the stream.
+/// ```
+/// # use mc_sim::drop_list;
+/// # use mc_sim::stream;
+/// let csv = "runs,barters,fights,successful_barters,successful_fights,target_pearls,target_rods
+/// 22,262,305,42,211,220,211";
+///
+/// let results = stream::read_observed_csv(csv.as_bytes()).unwrap();
+/// assert_eq!(results.len(), 1);
+/// assert_eq!(results[0].number_of_runs, 22);
+/// assert_eq!(results[0].total_barters, 262);
+/// assert_eq!(results[0].average_target_pearls_per_run(), 10);
+/// assert_eq!(results[0].rod_luck(&drop_list::blaze_drop_list(211)), 0.000000000008791412042796765);
+/// ```
+pub fn read_observed_csv(reader: impl Read) -> Result<Vec<StreamResults>, McSimError> {
+    csv::Reader::from_reader(reader)
+        .deserialize()
+        .map(|record| {
+            let record: ObservedRecord = record?;
+            Ok(StreamResults::from_observed(
+                record.runs,
+                record.barters,
+                record.fights,
+                record.successful_barters,
+                record.successful_fights,
+                record.target_pearls,
+                record.target_pearls.checked_div(record.runs).unwrap_or(0),
+                record.target_rods,
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,62 @@
+//! Exercises the statistical core, which builds without the `threaded` feature:
+//! `cargo test --no-default-features --test core`
+
+use mc_sim::drop::{DropSim, Item};
+use mc_sim::drop_list;
+use mc_sim::run::RunGoals;
+use mc_sim::sim::SimulationGoalsBuilder;
+use mc_sim::stats;
+use mc_sim::stream::{Stream, StreamResults};
+
+#[test]
+fn attempts_to_reach_target_matches_the_dice_example() {
+    let attempts = stats::attempts_to_reach_target(1, 6, 30);
+    assert!((attempts - 9.0476).abs() < 1e-4);
+}
+
+#[test]
+fn distributions_can_be_built_and_queried() {
+    let barter_drop_list = drop_list::barter_drop_list(220, 10);
+    let blaze_drop_list = drop_list::blaze_drop_list(154);
+    let pearls = barter_drop_list.distribution().unwrap();
+    let rods = blaze_drop_list.distribution().unwrap();
+
+    assert_eq!(rods.expected_total_blazes(), 308.0);
+    assert!(pearls.expected_total_barters() > 900.0);
+    assert!(rods.luck(rods.quantile(0.5).unwrap()) >= 0.5);
+
+    let probability = stats::item_drop_probability(barter_drop_list.list(), Item::EnderPearl);
+    assert_eq!(probability, 20.0 / 423.0);
+}
+
+#[test]
+fn streams_can_be_simulated_and_summarized_without_threads() {
+    let goals = SimulationGoalsBuilder::new().add_runs(5, 10, 7).goals();
+    let barter_drop_list = drop_list::barter_drop_list(50, 10);
+    let blaze_drop_list = drop_list::blaze_drop_list(35);
+    let mut barter_drop_sim = DropSim::from_seed(barter_drop_list.list_clone(), 1);
+    let mut blaze_drop_sim = DropSim::from_seed(blaze_drop_list.list_clone(), 2);
+
+    let results: Vec<StreamResults> = (0..100)
+        .map(|_| {
+            Stream::simulate_results_only(
+                &mut barter_drop_sim,
+                &mut blaze_drop_sim,
+                &goals.streams[0],
+            )
+        })
+        .collect();
+
+    let summary = stats::summarize(&results, &barter_drop_list, &blaze_drop_list).unwrap();
+    assert_eq!(summary.streams, 100);
+
+    let lucks = stats::luck_all(&results, &barter_drop_list, &blaze_drop_list);
+    assert_eq!(lucks.len(), 100);
+    assert!(lucks.iter().all(|luck| (0.0..=1.0).contains(luck)));
+}
+
+#[test]
+fn run_goals_are_available_without_threads() {
+    let goals = RunGoals::pearls_only(10);
+    assert_eq!(goals.target_rods, 0);
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "threaded")]
+
+use mc_sim::drop_list;
+use mc_sim::sim::{Simulation, SimulationGoals};
+use mc_sim::stream::StreamResults;
+use std::sync::OnceLock;
+
+const RUNS: u32 = 22;
+const TARGET_PEARLS: u32 = 10;
+const TARGET_RODS: u32 = 7;
+const STREAMS: u64 = 2000;
+const SEED: u64 = 1;
+
+/// Simulates Dream's 22 runs on a single seeded worker thread, so that the results are the same every time.
+fn simulate_seeded_streams(seed: u64) -> Vec<StreamResults> {
+    Simulation::new_seeded(SimulationGoals::dream_scenario(), 1, seed)
+        .simulate_collecting(0, STREAMS)
+}
+
+/// The seeded streams, which are only simulated once for all of the tests.
+fn seeded_streams() -> &'static [StreamResults] {
+    static SEEDED: OnceLock<Vec<StreamResults>> = OnceLock::new();
+    SEEDED.get_or_init(|| simulate_seeded_streams(SEED))
+}
+
+fn mean(values: impl Iterator<Item = u32>) -> f64 {
+    values.map(f64::from).sum::<f64>() / STREAMS as f64
+}
+
+#[test]
+fn simulated_dream_streams_match_the_analytical_distributions() {
+    let results = seeded_streams();
+    let barter_drop_list = drop_list::barter_drop_list(RUNS * TARGET_PEARLS, TARGET_PEARLS);
+    let blaze_drop_list = drop_list::blaze_drop_list(RUNS * TARGET_RODS);
+
+    let expected_barters = barter_drop_list
+        .distribution()
+        .unwrap()
+        .expected_total_barters();
+    let expected_blazes = blaze_drop_list
+        .distribution()
+        .unwrap()
+        .expected_total_blazes();
+
+    let mean_barters = mean(results.iter().map(|r| r.total_barters));
+    let mean_blazes = mean(results.iter().map(|r| r.total_fights));
+
+    assert!(
+        (mean_barters - expected_barters).abs() / expected_barters < 0.02,
+        "mean barters {} is too far from the expected {}",
+        mean_barters,
+        expected_barters
+    );
+    assert!(
+        (mean_blazes - expected_blazes).abs() / expected_blazes < 0.02,
+        "mean blazes {} is too far from the expected {}",
+        mean_blazes,
+        expected_blazes
+    );
+}
+
+#[test]
+fn simulated_dream_streams_are_typically_lucky_around_half_the_time() {
+    let results = seeded_streams();
+    let barter_drop_list = drop_list::barter_drop_list(RUNS * TARGET_PEARLS, TARGET_PEARLS);
+    let blaze_drop_list = drop_list::blaze_drop_list(RUNS * TARGET_RODS);
+
+    // Luck is the probability of doing at least this well, so it should be roughly uniform over simulated streams.
+    let median_or_better = results
+        .iter()
+        .filter(|r| r.pearl_luck(&barter_drop_list) <= 0.5)
+        .count() as f64
+        / STREAMS as f64;
+    assert!((median_or_better - 0.5).abs() < 0.1, "{}", median_or_better);
+
+    let median_or_better = results
+        .iter()
+        .filter(|r| r.rod_luck(&blaze_drop_list) <= 0.5)
+        .count() as f64
+        / STREAMS as f64;
+    assert!((median_or_better - 0.5).abs() < 0.1, "{}", median_or_better);
+}
+
+#[test]
+fn seeded_streams_are_reproducible() {
+    assert_eq!(seeded_streams(), simulate_seeded_streams(SEED).as_slice());
+}